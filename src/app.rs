@@ -1,18 +1,21 @@
 use crate::{
+    LedgerDb,
     components::{Component, root::RootComponent},
     config::Config,
-    model::button::InputEvent,
-    otel::TraceGraphSnapshot,
+    metrics::model::MetricUpdate,
+    model::{button::InputEvent, ledger_refresh::LedgerRefresh},
+    otel::{TraceGraphSnapshot, service::CollectorStatusHandle},
     states::Action,
     tui::{Event, Tui},
 };
+use amaru_kernel::network::NetworkName;
 use amaru_stores::rocksdb::{ReadOnlyRocksDB, consensus::ReadOnlyChainDB};
 use anyhow::Result;
 use crossterm::event::{Event as CrosstermEvent, KeyEvent};
 use ratatui::prelude::{Backend, Rect};
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, mpsc};
-use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::sync::mpsc::{Receiver, UnboundedReceiver, UnboundedSender, unbounded_channel};
 use tracing::debug;
 
 pub struct App {
@@ -36,9 +39,14 @@ pub enum Mode {
 
 impl App {
     pub fn new(
-        ledger_db: ReadOnlyRocksDB,
+        config: Config,
+        ledger_db: LedgerDb,
         chain_db: ReadOnlyChainDB,
+        compare_ledger_db: Option<ReadOnlyRocksDB>,
         trace_graph: TraceGraphSnapshot,
+        metric_updates: Receiver<MetricUpdate>,
+        collector_status: CollectorStatusHandle,
+        network: NetworkName,
         button_events: mpsc::Receiver<InputEvent>,
         frame_area: Rect,
     ) -> Result<Self> {
@@ -49,12 +57,21 @@ impl App {
             frame_area,
             should_quit: false,
             should_suspend: false,
-            config: Config::new()?,
+            config,
             mode: Mode::default(),
             last_tick_key_events: Vec::new(),
             action_tx,
             action_rx,
-            root: RootComponent::new(Arc::new(ledger_db), Arc::new(chain_db), trace_graph),
+            root: RootComponent::new(
+                Arc::new(ledger_db.db),
+                LedgerRefresh::new(ledger_db.path, ledger_db.refresh_interval),
+                Arc::new(chain_db),
+                compare_ledger_db.map(Arc::new),
+                trace_graph,
+                metric_updates,
+                collector_status,
+                network,
+            ),
         })
     }
 
@@ -185,6 +202,7 @@ impl App {
                 Action::Render => {
                     self.render(tui)?;
                 }
+                Action::CopyToClipboard(text) => tui.copy_to_clipboard(&text)?,
                 _ => {}
             }
         }
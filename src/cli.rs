@@ -1,7 +1,7 @@
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf};
 
 use amaru_kernel::network::NetworkName;
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::config::{get_config_dir, get_data_dir};
 
@@ -16,6 +16,117 @@ pub struct Cli {
 
     #[arg(short, long, value_name = "FLOAT", env = "AMARU_CHAIN_DB")]
     pub chain_db: Option<PathBuf>,
+
+    /// Re-open the ledger db every SECONDS to catch up with the node writing
+    /// it, highlighting what changed. Without it, `R` refreshes on demand
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        env = "AMARU_REFRESH_INTERVAL",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub refresh_interval: Option<u64>,
+
+    /// Append the traces received from amaru to FILE, to replay them later
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+
+    /// Replay the traces recorded in FILE instead of listening for amaru
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// How many times faster than recorded to replay, 0 replays without pause
+    #[arg(long, value_name = "FACTOR", default_value_t = 1.0)]
+    pub replay_speed: f64,
+
+    /// Open the traces of an OTLP/JSON or Jaeger JSON FILE instead of
+    /// listening for amaru. Can be repeated
+    #[arg(
+        long = "open-traces",
+        value_name = "FILE",
+        conflicts_with_all = ["record", "replay"]
+    )]
+    pub open_traces: Vec<PathBuf>,
+
+    /// Listen for OTLP/gRPC traces and metrics on ADDR [default: 0.0.0.0:4317]
+    #[arg(long, value_name = "ADDR", env = "AMARU_OTEL_GRPC_ADDR")]
+    pub otel_grpc_addr: Option<SocketAddr>,
+
+    /// Listen for OTLP/HTTP traces and metrics on ADDR [default: 0.0.0.0:4318]
+    #[arg(long, value_name = "ADDR", env = "AMARU_OTEL_HTTP_ADDR")]
+    pub otel_http_addr: Option<SocketAddr>,
+
+    /// Don't listen for OTLP/gRPC
+    #[arg(long)]
+    pub no_otel_grpc: bool,
+
+    /// Don't listen for OTLP/HTTP
+    #[arg(long)]
+    pub no_otel_http: bool,
+
+    /// Keep received traces for SECONDS [default: 600]
+    #[arg(long, value_name = "SECONDS", env = "AMARU_TRACE_RETENTION")]
+    pub trace_retention: Option<u64>,
+
+    /// Keep at most SPANS spans, evicting the oldest traces [default: 1000000]
+    #[arg(long, value_name = "SPANS", env = "AMARU_MAX_SPANS")]
+    pub max_spans: Option<usize>,
+
+    /// Keep spans taking at most about MIB MiB, evicting the oldest traces
+    /// [default: 512]
+    #[arg(long, value_name = "MIB", env = "AMARU_MAX_TRACE_MEMORY")]
+    pub max_trace_memory: Option<usize>,
+
+    /// Queue at most BATCHES span batches before slowing exporters down
+    /// [default: 10000]
+    #[arg(long, value_name = "BATCHES", env = "AMARU_TRACE_QUEUE")]
+    pub trace_queue: Option<usize>,
+
+    /// A second ledger db to compare the ledger db with
+    #[arg(long, value_name = "PATH", env = "AMARU_COMPARE_LEDGER_DB")]
+    pub compare_ledger_db: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Looks up ledger entries and prints them to stdout without starting the TUI
+    Query {
+        /// The output format of the printed entries
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Json)]
+        format: OutputFormat,
+
+        #[command(subcommand)]
+        query: LedgerQuery,
+    },
+    /// Compares the ledger db with the one given by --compare-ledger-db and
+    /// prints how many entries differ in each column
+    Diff {
+        /// Also print every difference, as JSON lines
+        #[arg(long)]
+        list: bool,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum LedgerQuery {
+    /// An account, by its hex-encoded stake credential hash
+    Account { credential: String },
+    /// A pool, by its hex-encoded pool id
+    Pool { pool_id: String },
+    /// All UTXOs held at an address
+    Utxos {
+        #[arg(long)]
+        address: String,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    CborDiag,
 }
 
 const VERSION_MESSAGE: &str = concat!(
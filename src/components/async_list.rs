@@ -1,6 +1,8 @@
 use crate::{
-    components::list::ListModel, model::async_provider::AsyncProvider,
-    ui::to_list_item::ToListItem, view::list::ListViewState,
+    components::list::ListModel,
+    model::{async_provider::AsyncProvider, table_columns::TableColumns},
+    ui::{to_list_item::ToListItem, to_table_row::ToTableRow},
+    view::list::ListViewState,
 };
 use ratatui::{
     Frame,
@@ -11,6 +13,7 @@ use ratatui::{
 };
 use std::marker::PhantomData;
 use tokio::sync::mpsc::error::TryRecvError;
+use tracing::info;
 
 /// A data model for a list whose data is loaded asynchronously.
 /// It implements `ListModel` so it can be wrapped by a `ListComponent` or used
@@ -19,10 +22,13 @@ pub struct AsyncListModel<T>
 where
     T: ToListItem + Send + Sync + 'static,
 {
-    provider: AsyncProvider<T>,
+    /// `None` while waiting for what the items are computed from.
+    provider: Option<AsyncProvider<T>>,
     pub buffer: Vec<T>,
     pub view: ListViewState,
     is_loading: bool,
+    /// The items kept at most, the provider is dropped once reached.
+    limit: Option<usize>,
     _phantom: PhantomData<T>,
 }
 
@@ -32,14 +38,78 @@ where
 {
     pub fn new(title: &'static str, provider: AsyncProvider<T>) -> Self {
         Self {
-            provider,
+            provider: Some(provider),
+            ..Self::waiting(title)
+        }
+    }
+
+    /// A list shown as loading until it is replaced by one with a provider.
+    pub fn waiting(title: &'static str) -> Self {
+        Self {
+            provider: None,
             buffer: Vec::new(),
             view: ListViewState::new(title),
             is_loading: true,
+            limit: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Stops loading after the first `limit` items.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
+    /// Whether the list still waits for a provider.
+    pub fn is_waiting(&self) -> bool {
+        self.is_loading && self.provider.is_none()
+    }
+
+    fn draw_loading(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        let mut block = Block::default()
+            .title(self.view.title())
+            .borders(Borders::ALL);
+
+        if is_focused {
+            block = block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+
+        let loading_text = vec![
+            Line::from(""),
+            Line::from(Span::styled("Loading...", Style::default().fg(Color::Gray))),
+        ];
+
+        let widget = Paragraph::new(loading_text)
+            .alignment(Alignment::Center)
+            .block(block);
+
+        f.render_widget(widget, area);
+    }
+
+    /// Same as `draw`, with the style `row_style` returns for some of the
+    /// items.
+    pub fn draw_styled(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        is_focused: bool,
+        row_style: impl Fn(&T) -> Option<Style>,
+    ) {
+        if self.is_loading && self.buffer.is_empty() {
+            self.draw_loading(f, area, is_focused);
+        } else {
+            self.view
+                .draw_styled(f, area, &self.buffer, is_focused, row_style);
+        }
+    }
+
     /// Polls the provider for new data without blocking.
     /// This should be called on every application tick via the parent component.
     pub fn poll_data(&mut self) {
@@ -47,13 +117,27 @@ where
         if !self.is_loading {
             return;
         }
+        let Some(provider) = self.provider.as_mut() else {
+            return;
+        };
 
         // Poll up to 100 items per tick to avoid blocking
         // the UI for too long on a fast data influx.
         for _ in 0..100 {
-            match self.provider.rx.try_recv() {
+            match provider.rx.try_recv() {
                 Ok(item) => {
                     self.buffer.push(item);
+                    if self.limit.is_some_and(|limit| self.buffer.len() >= limit) {
+                        info!(
+                            "{}: showing the first {} items only",
+                            self.view.title(),
+                            self.buffer.len()
+                        );
+                        // Dropping the receiver stops the provider
+                        self.provider = None;
+                        self.is_loading = false;
+                        break;
+                    }
                 }
                 Err(TryRecvError::Empty) => {
                     // No more data right now
@@ -69,39 +153,46 @@ where
     }
 }
 
-impl<T> ListModel for AsyncListModel<T>
+impl<T> AsyncListModel<T>
 where
-    T: ToListItem + Send + Sync + 'static,
+    T: ToListItem + ToTableRow + Send + Sync + 'static,
 {
-    type Item = T;
+    /// Draws the list as a table, once some data arrived.
+    pub fn draw_table(&self, f: &mut Frame, area: Rect, columns: &TableColumns, is_focused: bool) {
+        self.draw_table_styled(f, area, columns, is_focused, |_| None);
+    }
 
-    fn draw(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+    /// Same as `draw_table`, with the style `row_style` returns for some of
+    /// the rows.
+    pub fn draw_table_styled(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        columns: &TableColumns,
+        is_focused: bool,
+        row_style: impl Fn(&T) -> Option<Style>,
+    ) {
         if self.is_loading && self.buffer.is_empty() {
-            // Show a Loading message
-            let mut block = Block::default()
-                .title(self.view.title())
-                .borders(Borders::ALL);
-
-            if is_focused {
-                block = block
-                    .border_style(Style::default().fg(Color::Blue))
-                    .title_style(Style::default().fg(Color::White));
-            }
+            self.draw_loading(f, area, is_focused);
+        } else {
+            self.view
+                .draw_table_styled(f, area, &self.buffer, columns, is_focused, row_style);
+        }
+    }
+}
 
-            let loading_text = vec![
-                Line::from(""),
-                Line::from(Span::styled("Loading...", Style::default().fg(Color::Gray))),
-            ];
+impl<T> ListModel for AsyncListModel<T>
+where
+    T: ToListItem + Send + Sync + 'static,
+{
+    type Item = T;
 
-            let widget = Paragraph::new(loading_text)
-                .alignment(Alignment::Center)
-                .block(block);
+    fn poll(&mut self) {
+        self.poll_data();
+    }
 
-            f.render_widget(widget, area);
-        } else {
-            // Render the list as normal
-            self.view.draw(f, area, &self.buffer, is_focused);
-        }
+    fn draw(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        self.draw_styled(f, area, is_focused, |_| None);
     }
 
     fn selected_item(&self) -> Option<&T> {
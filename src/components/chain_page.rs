@@ -1,12 +1,12 @@
 use crate::{
     components::{
         Component, ComponentLayout, chain_search::ChainSearchComponent,
-        search_bar::SearchBarComponent,
+        fork_tree::ForkTreeComponent, search_bar::SearchBarComponent,
     },
     controller::{LayoutSpec, MoveFocus, find_next_focus, walk_layout},
     states::{Action, ComponentId},
 };
-use amaru_stores::rocksdb::consensus::ReadOnlyChainDB;
+use amaru_stores::rocksdb::{ReadOnlyRocksDB, consensus::ReadOnlyChainDB};
 use crossterm::event::Event;
 use either::Either::{Left, Right};
 use ratatui::{
@@ -23,16 +23,22 @@ pub struct ChainPageComponent {
     id: ComponentId,
     pub search_bar: SearchBarComponent,
     pub chain_search: ChainSearchComponent,
+    pub fork_tree: ForkTreeComponent,
     last_layout: RwLock<ComponentLayout>,
     active_focus: RwLock<ComponentId>,
 }
 
 impl ChainPageComponent {
-    pub fn new(chain_db: Arc<ReadOnlyChainDB>) -> Self {
+    pub fn new(chain_db: Arc<ReadOnlyChainDB>, ledger_db: Arc<ReadOnlyRocksDB>) -> Self {
         Self {
             id: ComponentId::ChainPage,
             search_bar: SearchBarComponent::new(ComponentId::SearchBar),
-            chain_search: ChainSearchComponent::new(ComponentId::ChainSearch, chain_db),
+            chain_search: ChainSearchComponent::new(
+                ComponentId::ChainSearch,
+                chain_db.clone(),
+                ledger_db,
+            ),
+            fork_tree: ForkTreeComponent::new(ComponentId::ChainForkTree, chain_db),
             last_layout: RwLock::new(HashMap::new()),
             active_focus: RwLock::new(ComponentId::SearchBar),
         }
@@ -49,7 +55,8 @@ impl ChainPageComponent {
                         constraints: vec![(Constraint::Fill(1), Left(ComponentId::SearchBar))],
                     }),
                 ),
-                (Constraint::Fill(1), Left(ComponentId::ChainSearch)),
+                (Constraint::Fill(2), Left(ComponentId::ChainSearch)),
+                (Constraint::Fill(1), Left(ComponentId::ChainForkTree)),
             ],
         };
 
@@ -96,6 +103,11 @@ impl ChainPageComponent {
             let is_focused = current_focus == ComponentId::ChainSearch;
             self.chain_search.render_focused(frame, *rect, is_focused);
         }
+
+        if let Some(rect) = my_layout.get(&ComponentId::ChainForkTree) {
+            let is_focused = current_focus == ComponentId::ChainForkTree;
+            self.fork_tree.render_focused(frame, *rect, is_focused);
+        }
     }
 }
 
@@ -110,9 +122,17 @@ impl Component for ChainPageComponent {
         self
     }
 
+    fn tick(&mut self) -> Vec<Action> {
+        let mut actions = self.chain_search.tick();
+        self.fork_tree.set_selected(self.chain_search.selected());
+        actions.extend(self.fork_tree.tick());
+        actions
+    }
+
     fn handle_event(&mut self, event: &Event, area: Rect) -> Vec<Action> {
         let layout = self.last_layout.read().unwrap().clone();
         let mut active_focus = *self.active_focus.read().unwrap();
+        let tree_selection = self.fork_tree.selected();
 
         let mut actions = crate::components::handle_container_event(
             &layout,
@@ -125,6 +145,8 @@ impl Component for ChainPageComponent {
                     acts.extend(self.search_bar.handle_event(ev, child_area));
                 } else if target_id == ComponentId::ChainSearch {
                     acts.extend(self.chain_search.handle_event(ev, child_area));
+                } else if target_id == ComponentId::ChainForkTree {
+                    acts.extend(self.fork_tree.handle_event(ev, child_area));
                 }
                 acts
             },
@@ -138,6 +160,14 @@ impl Component for ChainPageComponent {
             self.handle_search(&query);
         }
 
+        // Moving through the fork tree browses to the selected header
+        if let Some(hash) = self.fork_tree.selected()
+            && self.fork_tree.selected() != tree_selection
+        {
+            self.chain_search.browse_to(hash);
+        }
+        self.fork_tree.set_selected(self.chain_search.selected());
+
         *self.active_focus.write().unwrap() = active_focus;
         actions
     }
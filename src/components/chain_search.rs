@@ -1,55 +1,235 @@
 use crate::{
     components::Component,
-    model::search::SearchCache,
+    model::{
+        async_provider::AsyncProvider,
+        block::{DecodedBlock, TransactionSummary},
+        chain_query::{ChainQuery, TX_SCAN_DEPTH},
+        search::SearchCache,
+    },
     states::{Action, ComponentId},
-    view::item_details::draw_details,
+    view::item_details::{draw_details, draw_details_scrolled},
 };
-use amaru_consensus::{BlockHeader, Nonces, ReadOnlyChainStore};
-use amaru_kernel::{Hash, RawBlock};
-use amaru_stores::rocksdb::consensus::ReadOnlyChainDB;
-use crossterm::event::{Event, MouseButton, MouseEventKind};
+use amaru_consensus::{BlockHeader, IsHeader, Nonces, ReadOnlyChainStore};
+use amaru_kernel::Hash;
+use amaru_stores::rocksdb::{ReadOnlyRocksDB, consensus::ReadOnlyChainDB};
+use crossterm::event::{Event, KeyCode, MouseButton, MouseEventKind};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState},
 };
 use std::{any::Any, str::FromStr, sync::Arc};
+use tokio::sync::mpsc::error::TryRecvError;
 use tracing::warn;
 
-pub type ChainResult = (BlockHeader, RawBlock, Nonces);
+pub type ChainResult = (BlockHeader, DecodedBlock, Nonces);
+
+/// A query being resolved in the background, as transaction lookups decode
+/// up to thousands of blocks.
+struct PendingQuery {
+    query: ChainQuery,
+    query_str: String,
+    provider: AsyncProvider<Vec<Hash<32>>>,
+}
 
 pub struct ChainSearchComponent {
     id: ComponentId,
     db: Arc<ReadOnlyChainDB>,
+    ledger_db: Arc<ReadOnlyRocksDB>,
     state: SearchCache<Hash<32>, ChainResult>,
+    /// The headers matching the last query, more than one when it is ambiguous
+    /// (e.g. forks sharing a slot). Left/Right cycles through them.
+    candidates: Vec<Hash<32>>,
+    candidate_idx: usize,
+    /// The selected transaction of the displayed block.
+    tx_idx: usize,
+    tx_scroll: u16,
     focused_column: Option<usize>,
+    pending: Option<PendingQuery>,
+    /// Why the last query has no result, shown until the next one.
+    not_found: Option<String>,
 }
 
+const HEADER_CHUNK: usize = 0;
+const TRANSACTIONS_CHUNK: usize = 1;
+const TRANSACTION_DETAILS_CHUNK: usize = 2;
+const NONCES_CHUNK: usize = 3;
+
 impl ChainSearchComponent {
-    pub fn new(id: ComponentId, db: Arc<ReadOnlyChainDB>) -> Self {
+    pub fn new(id: ComponentId, db: Arc<ReadOnlyChainDB>, ledger_db: Arc<ReadOnlyRocksDB>) -> Self {
         Self {
             id,
             db,
+            ledger_db,
             state: SearchCache::default(),
+            candidates: Vec::new(),
+            candidate_idx: 0,
+            tx_idx: 0,
+            tx_scroll: 0,
             focused_column: None,
+            pending: None,
+            not_found: None,
         }
     }
 
-    fn get_layout_chunks(&self, area: Rect) -> std::rc::Rc<[Rect]> {
-        Layout::default()
+    /// Returns the header, transactions, transaction details and nonces areas.
+    fn get_layout_chunks(&self, area: Rect) -> [Rect; 4] {
+        let [left, transactions, details] = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Fill(1),
                 Constraint::Fill(1),
                 Constraint::Fill(1),
             ])
-            .split(area)
+            .areas(area);
+        let [header, nonces] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(2), Constraint::Fill(1)])
+            .areas(left);
+        [header, transactions, details, nonces]
     }
 
     pub fn handle_search(&mut self, query_str: &str) {
-        let Ok(hash) = Hash::<32>::from_str(query_str) else {
-            warn!("Invalid hash format: {}", query_str);
+        let query = match ChainQuery::from_str(query_str) {
+            Ok(query) => query,
+            Err(e) => {
+                warn!("Invalid chain query {}: {}", query_str, e);
+                return;
+            }
+        };
+
+        // A new query replaces the pending one, whose result is dropped
+        let db = self.db.clone();
+        let resolved = query.clone();
+        self.pending = Some(PendingQuery {
+            query,
+            query_str: query_str.to_string(),
+            provider: AsyncProvider::new(move |tx| {
+                let _ = tx.blocking_send(resolved.resolve(&db));
+            }),
+        });
+    }
+
+    /// Displays the result of the pending query once it is resolved.
+    fn poll_pending(&mut self) {
+        let Some(pending) = self.pending.as_mut() else {
+            return;
+        };
+        let candidates = match pending.provider.rx.try_recv() {
+            Ok(candidates) => candidates,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Vec::new(),
+        };
+        let Some(PendingQuery {
+            query, query_str, ..
+        }) = self.pending.take()
+        else {
             return;
         };
+        if candidates.is_empty() {
+            // Clear the previous block, so it isn't taken for the result
+            self.not_found = Some(match query {
+                ChainQuery::Transaction(_) => format!(
+                    "{} not found in the last {} blocks",
+                    query_str, TX_SCAN_DEPTH
+                ),
+                _ => format!("no header found for {}", query_str),
+            });
+            self.candidates.clear();
+            self.candidate_idx = 0;
+            self.state.parsed = None;
+            return;
+        }
+
+        self.candidates = candidates;
+        self.candidate_idx = 0;
+        self.select(self.candidates[0]);
+
+        if let ChainQuery::Transaction(id) = query {
+            self.select_transaction(&id);
+        }
+    }
+
+    /// Selects the transaction with the given id in the displayed block.
+    fn select_transaction(&mut self, id: &Hash<32>) {
+        let position = self
+            .state
+            .get_current_res()
+            .and_then(|(_, block, _)| block.transactions.as_ref().ok())
+            .and_then(|txs| txs.iter().position(|tx| tx.id == *id));
+        if let Some(position) = position {
+            self.tx_idx = position;
+        }
+    }
+
+    /// The hash of the header currently displayed.
+    pub fn selected(&self) -> Option<Hash<32>> {
+        self.state.parsed
+    }
+
+    /// The id of the transaction selected in the displayed block.
+    fn selected_transaction_id(&self) -> Option<Hash<32>> {
+        self.state
+            .get_current_res()
+            .and_then(|(_, block, _)| block.transactions.as_ref().ok())
+            .and_then(|txs| txs.get(self.tx_idx))
+            .map(|tx| tx.id)
+    }
+
+    /// Displays the given header, replacing the candidates of the last query.
+    pub fn browse_to(&mut self, hash: Hash<32>) {
+        self.candidates = vec![hash];
+        self.candidate_idx = 0;
+        self.select(hash);
+    }
+
+    fn browse_parent(&mut self) {
+        let parent = self
+            .state
+            .get_current_res()
+            .and_then(|(header, _, _)| header.parent());
+        match parent {
+            Some(parent) => self.browse_to(parent),
+            None => warn!("Selected header has no parent"),
+        }
+    }
+
+    /// Moves to the known children of the selected header, which can then be
+    /// cycled through like ambiguous query results.
+    fn browse_children(&mut self) {
+        let Some(hash) = self.selected() else {
+            return;
+        };
+        let children = ReadOnlyChainStore::<BlockHeader>::get_children(&*self.db, &hash);
+        if children.is_empty() {
+            warn!("No known children for {}", hash);
+            return;
+        }
+        self.candidates = children;
+        self.candidate_idx = 0;
+        self.select(self.candidates[0]);
+    }
+
+    /// Cycles through the candidates of an ambiguous query.
+    fn cycle_candidate(&mut self, forward: bool) {
+        let len = self.candidates.len();
+        if len < 2 {
+            return;
+        }
+        self.candidate_idx = if forward {
+            (self.candidate_idx + 1) % len
+        } else {
+            (self.candidate_idx + len - 1) % len
+        };
+        self.select(self.candidates[self.candidate_idx]);
+    }
+
+    fn select(&mut self, hash: Hash<32>) {
+        self.not_found = None;
+        self.tx_idx = 0;
+        self.tx_scroll = 0;
 
         // Check Cache
         if self.state.results.contains_key(&hash) {
@@ -62,26 +242,175 @@ impl ChainSearchComponent {
         let nonces_opt = ReadOnlyChainStore::<BlockHeader>::get_nonces(&*self.db, &hash);
 
         if let (Some(header), Ok(block), Some(nonces)) = (header_opt, block_res, nonces_opt) {
+            let mut block = DecodedBlock::from(block);
+            if let Ok(transactions) = block.transactions.as_mut() {
+                for tx in transactions {
+                    tx.check_unspent_outputs(&self.ledger_db);
+                }
+            }
             self.state.cache_result(hash, (header, block, nonces));
         }
     }
 
+    fn transactions_len(&self) -> usize {
+        self.state
+            .get_current_res()
+            .and_then(|(_, block, _)| block.transactions.as_ref().ok())
+            .map_or(0, |txs| txs.len())
+    }
+
+    fn move_transaction(&mut self, forward: bool) {
+        let len = self.transactions_len();
+        if len == 0 {
+            return;
+        }
+        self.tx_idx = if forward {
+            (self.tx_idx + 1).min(len - 1)
+        } else {
+            self.tx_idx.saturating_sub(1)
+        };
+        self.tx_scroll = 0;
+    }
+
+    fn scroll_details(&mut self, forward: bool) {
+        self.tx_scroll = if forward {
+            self.tx_scroll.saturating_add(1)
+        } else {
+            self.tx_scroll.saturating_sub(1)
+        };
+    }
+
+    fn render_block(
+        &self,
+        f: &mut Frame,
+        chunks: &[Rect; 4],
+        block: &DecodedBlock,
+        focus: &[bool],
+    ) {
+        let transactions = match &block.transactions {
+            Ok(transactions) => transactions,
+            Err(e) => {
+                draw_details(
+                    f,
+                    chunks[TRANSACTIONS_CHUNK],
+                    format!("Raw Block (undecodable: {})", e),
+                    Some(&block.raw),
+                    focus[TRANSACTIONS_CHUNK],
+                );
+                draw_details::<TransactionSummary>(
+                    f,
+                    chunks[TRANSACTION_DETAILS_CHUNK],
+                    "Transaction Details".to_string(),
+                    None,
+                    focus[TRANSACTION_DETAILS_CHUNK],
+                );
+                return;
+            }
+        };
+
+        let mut list_block = Block::default()
+            .title(format!("Transactions ({})", transactions.len()))
+            .borders(Borders::ALL);
+        if focus[TRANSACTIONS_CHUNK] {
+            list_block = list_block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+        let items: Vec<ListItem> = transactions
+            .iter()
+            .map(|tx| {
+                let style = if tx.is_valid {
+                    Style::default()
+                } else {
+                    Style::default().fg(Color::Red)
+                };
+                ListItem::new(Line::styled(
+                    format!(
+                        "#{} {} ({} in, {} out)",
+                        tx.index,
+                        tx.id,
+                        tx.inputs.len(),
+                        tx.outputs.len()
+                    ),
+                    style,
+                ))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(list_block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.tx_idx));
+        f.render_stateful_widget(list, chunks[TRANSACTIONS_CHUNK], &mut state);
+
+        draw_details_scrolled(
+            f,
+            chunks[TRANSACTION_DETAILS_CHUNK],
+            "Transaction Details".to_string(),
+            transactions.get(self.tx_idx),
+            focus[TRANSACTION_DETAILS_CHUNK],
+            self.tx_scroll,
+        );
+    }
+
     pub fn render_focused(&self, f: &mut Frame, area: Rect, is_focused: bool) {
         let chunks = self.get_layout_chunks(area);
 
         let result = self.state.get_current_res();
         let header = result.map(|r| &r.0);
-        let block = result.map(|r| &r.1);
         let nonces = result.map(|r| &r.2);
 
         // Highlight specific columns if globally focused (and specific column selected)
-        let f0 = is_focused && self.focused_column == Some(0);
-        let f1 = is_focused && self.focused_column == Some(1);
-        let f2 = is_focused && self.focused_column == Some(2);
+        let focus: Vec<bool> = (0..chunks.len())
+            .map(|i| is_focused && self.focused_column == Some(i))
+            .collect();
 
-        draw_details(f, chunks[0], "Header Details".to_string(), header, f0);
-        draw_details(f, chunks[1], "Block Details".to_string(), block, f1);
-        draw_details(f, chunks[2], "Nonces Details".to_string(), nonces, f2);
+        let header_title = if let Some(pending) = &self.pending {
+            format!("Header Details (searching {}...)", pending.query_str)
+        } else if let Some(not_found) = &self.not_found {
+            format!("Header Details ({})", not_found)
+        } else if self.candidates.len() > 1 {
+            format!(
+                "Header Details ({}/{}, ←/→ to cycle, p: parent, c: children)",
+                self.candidate_idx + 1,
+                self.candidates.len()
+            )
+        } else {
+            "Header Details (p: parent, c: children)".to_string()
+        };
+
+        draw_details(
+            f,
+            chunks[HEADER_CHUNK],
+            header_title,
+            header,
+            focus[HEADER_CHUNK],
+        );
+        draw_details(
+            f,
+            chunks[NONCES_CHUNK],
+            "Nonces Details".to_string(),
+            nonces,
+            focus[NONCES_CHUNK],
+        );
+        match result {
+            Some((_, block, _)) => self.render_block(f, &chunks, block, &focus),
+            None => {
+                draw_details::<TransactionSummary>(
+                    f,
+                    chunks[TRANSACTIONS_CHUNK],
+                    "Transactions".to_string(),
+                    None,
+                    focus[TRANSACTIONS_CHUNK],
+                );
+                draw_details::<TransactionSummary>(
+                    f,
+                    chunks[TRANSACTION_DETAILS_CHUNK],
+                    "Transaction Details".to_string(),
+                    None,
+                    focus[TRANSACTION_DETAILS_CHUNK],
+                );
+            }
+        }
     }
 }
 
@@ -96,7 +425,50 @@ impl Component for ChainSearchComponent {
         self
     }
 
+    fn tick(&mut self) -> Vec<Action> {
+        self.poll_pending();
+        Vec::new()
+    }
+
     fn handle_event(&mut self, event: &Event, area: Rect) -> Vec<Action> {
+        if let Event::Key(key) = event {
+            match key.code {
+                KeyCode::Left => self.cycle_candidate(false),
+                KeyCode::Right => self.cycle_candidate(true),
+                KeyCode::Char('y') => {
+                    if let Some(hash) = self.selected() {
+                        return vec![Action::CopyToClipboard(hash.to_string())];
+                    }
+                }
+                KeyCode::Char('Y') => {
+                    if let Some(id) = self.selected_transaction_id() {
+                        return vec![Action::CopyToClipboard(id.to_string())];
+                    }
+                }
+                KeyCode::Char('p') => self.browse_parent(),
+                KeyCode::Char('c') => self.browse_children(),
+                KeyCode::Up => self.move_transaction(false),
+                KeyCode::Down => self.move_transaction(true),
+                KeyCode::PageUp => self.scroll_details(false),
+                KeyCode::PageDown => self.scroll_details(true),
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse) = event
+            && matches!(
+                mouse.kind,
+                MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+            )
+        {
+            let forward = mouse.kind == MouseEventKind::ScrollDown;
+            match self.focused_column {
+                Some(TRANSACTIONS_CHUNK) => self.move_transaction(forward),
+                Some(TRANSACTION_DETAILS_CHUNK) => self.scroll_details(forward),
+                _ => {}
+            }
+        }
+
         if let Event::Mouse(mouse) = event
             && (mouse.kind == MouseEventKind::Moved
                 || mouse.kind == MouseEventKind::Down(MouseButton::Left))
@@ -1,8 +1,9 @@
 use crate::{
     components::{Component, ScrollDirection},
-    states::{Action, ComponentId},
+    export::{ExportJob, Exportable, spawn_export},
+    states::{Action, ComponentId, ExportFormat},
     ui::ToRichText,
-    view::item_details::draw_details,
+    view::item_details::{draw_details, draw_side_by_side},
 };
 use crossterm::event::{Event, KeyCode, MouseButton, MouseEventKind};
 use ratatui::{Frame, layout::Rect};
@@ -11,6 +12,7 @@ use std::{
     marker::PhantomData,
     sync::atomic::{AtomicBool, Ordering},
 };
+use tracing::warn;
 
 pub struct DetailsComponent<T>
 where
@@ -42,6 +44,39 @@ where
         draw_details(f, area, self.title.to_string(), item, is_focused);
     }
 
+    /// Renders two versions of an item next to each other, for diffs.
+    pub fn render_side_by_side(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        is_focused: bool,
+        left: Option<&T>,
+        right: Option<&T>,
+    ) {
+        self.is_focused.store(is_focused, Ordering::Relaxed);
+        draw_side_by_side(
+            f,
+            area,
+            ("Ledger", "Compared"),
+            (left, right),
+            is_focused,
+            self.scroll_offset,
+        );
+    }
+
+    /// Exports the displayed item. The item is passed in as the details only
+    /// render it, and may be exported as a different type than the one shown.
+    pub fn export<U>(&self, item: Option<&U>, format: ExportFormat) -> Option<ExportJob>
+    where
+        U: Exportable + Clone + Send + 'static,
+    {
+        let Some(item) = item.cloned() else {
+            warn!("Nothing to export in {}", self.id);
+            return None;
+        };
+        Some(spawn_export(self.id.to_string(), format, move || [item]))
+    }
+
     fn perform_scroll(&mut self, direction: ScrollDirection) {
         match direction {
             ScrollDirection::Up => {
@@ -0,0 +1,176 @@
+use crate::{
+    components::Component,
+    model::{
+        async_provider::AsyncProvider,
+        fork_tree::{ForkTree, ForkTreeLine},
+    },
+    states::{Action, ComponentId},
+};
+use amaru_kernel::Hash;
+use amaru_stores::rocksdb::consensus::ReadOnlyChainDB;
+use crossterm::event::{Event, KeyCode, MouseEventKind};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+use std::{any::Any, sync::Arc};
+use tokio::sync::mpsc::error::TryRecvError;
+
+/// How many blocks below the highest stored header are shown.
+const FORK_TREE_DEPTH: u64 = 100;
+
+/// The tree is reloaded every this many ticks, to pick up new headers.
+const REFRESH_TICKS: usize = 20;
+
+pub struct ForkTreeComponent {
+    id: ComponentId,
+    db: Arc<ReadOnlyChainDB>,
+    lines: Vec<ForkTreeLine>,
+    /// The reload running in the background, if any.
+    job: Option<AsyncProvider<Vec<ForkTreeLine>>>,
+    selected: Option<Hash<32>>,
+    ticks: usize,
+}
+
+impl ForkTreeComponent {
+    pub fn new(id: ComponentId, db: Arc<ReadOnlyChainDB>) -> Self {
+        let mut component = Self {
+            id,
+            db,
+            lines: Vec::new(),
+            job: None,
+            selected: None,
+            ticks: 0,
+        };
+        component.refresh();
+        component
+    }
+
+    /// Reloads the tree in the background, unless a reload is still running.
+    fn refresh(&mut self) {
+        if self.job.is_some() {
+            return;
+        }
+        let db = self.db.clone();
+        self.job = Some(AsyncProvider::new(move |tx| {
+            let _ = tx.blocking_send(ForkTree::load(&db, FORK_TREE_DEPTH).lines());
+        }));
+    }
+
+    fn poll(&mut self) {
+        let Some(job) = self.job.as_mut() else {
+            return;
+        };
+        match job.rx.try_recv() {
+            Ok(lines) => {
+                self.lines = lines;
+                self.job = None;
+            }
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => self.job = None,
+        }
+    }
+
+    pub fn selected(&self) -> Option<Hash<32>> {
+        self.selected
+    }
+
+    pub fn set_selected(&mut self, hash: Option<Hash<32>>) {
+        self.selected = hash;
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        self.selected
+            .and_then(|hash| self.lines.iter().position(|l| l.hash == hash))
+    }
+
+    fn move_selection(&mut self, forward: bool) {
+        if self.lines.is_empty() {
+            return;
+        }
+        let idx = match self.selected_index() {
+            Some(i) if forward => (i + 1).min(self.lines.len() - 1),
+            Some(i) => i.saturating_sub(1),
+            // Start from the newest header
+            None => self.lines.len() - 1,
+        };
+        self.selected = Some(self.lines[idx].hash);
+    }
+
+    pub fn render_focused(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        let mut block = Block::default()
+            .title("Fork Tree (best chain in green)")
+            .borders(Borders::ALL);
+        if is_focused {
+            block = block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+
+        let items: Vec<ListItem> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let style = if line.on_best_chain {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(line.prefix.clone()),
+                    Span::styled(
+                        format!("#{} slot {} {}", line.height, line.slot, line.hash),
+                        style,
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(self.selected_index());
+        f.render_stateful_widget(list, area, &mut state);
+    }
+}
+
+impl Component for ForkTreeComponent {
+    fn id(&self) -> ComponentId {
+        self.id
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn tick(&mut self) -> Vec<Action> {
+        self.poll();
+        self.ticks += 1;
+        if self.ticks.is_multiple_of(REFRESH_TICKS) {
+            self.refresh();
+        }
+        Vec::new()
+    }
+
+    fn handle_event(&mut self, event: &Event, _area: Rect) -> Vec<Action> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Up => self.move_selection(false),
+                KeyCode::Down => self.move_selection(true),
+                _ => {}
+            },
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::ScrollUp => self.move_selection(false),
+                MouseEventKind::ScrollDown => self.move_selection(true),
+                _ => {}
+            },
+            _ => {}
+        }
+        Vec::new()
+    }
+}
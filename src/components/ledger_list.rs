@@ -0,0 +1,463 @@
+use crate::{
+    components::{async_list::AsyncListModel, list::ListModel},
+    export::{ExportJob, Exportable, spawn_export},
+    model::{
+        async_provider::AsyncProvider,
+        ledger_diff::DiffKind,
+        ledger_filter::{Filter, LedgerFields, SortKey},
+        ledger_refresh::RowChanges,
+        list_view::ListModelView,
+        table_columns::TableColumns,
+    },
+    states::{Action, ExportFormat},
+    ui::{diff_style, to_list_item::ToListItem, to_table_row::ToTableRow},
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+type IterFactory<T> = Arc<dyn Fn() -> Box<dyn Iterator<Item = T> + Send + Sync> + Send + Sync>;
+
+/// Height of the filter bar drawn under the list, borders included.
+const FILTER_BAR_HEIGHT: usize = 3;
+
+/// How far past the removed entries the selection is looked for on reload.
+const RESELECT_LOOKAHEAD: usize = 1_000;
+
+/// How many items a sorted list holds, sorting a whole column such as the
+/// UTXO set not fitting in memory.
+const SORT_LIMIT: usize = 10_000;
+
+/// A ledger browse list that can be filtered and sorted, and drawn either as
+/// a list of labels or as a table.
+///
+/// The plain list streams lazily from the store. Once a filter or a sort is
+/// set, the matching items are materialised in the background from a fresh
+/// iterator, so the UI stays responsive on large columns.
+pub struct LedgerListModel<T>
+where
+    T: ToListItem + Exportable + LedgerFields + Send + Sync + 'static,
+{
+    title: &'static str,
+    source: IterFactory<T>,
+    all: ListModelView<T>,
+    filtered: Option<AsyncListModel<T>>,
+    filter: Option<Filter>,
+    filter_input: String,
+    sort: Option<SortKey>,
+    /// The filter being typed, while the prompt is open.
+    prompt: Option<String>,
+    error: Option<String>,
+    show_table: bool,
+    columns: TableColumns,
+    height: usize,
+    /// How entries changed on the last reload, by identifier.
+    changes: HashMap<String, DiffKind>,
+}
+
+impl<T> LedgerListModel<T>
+where
+    T: ToListItem + Exportable + LedgerFields + Send + Sync + 'static,
+{
+    pub fn new<F, I>(title: &'static str, source: F, initial_buffer_size: usize) -> Self
+    where
+        F: Fn() -> I + Send + Sync + 'static,
+        I: Iterator<Item = T> + Send + Sync + 'static,
+    {
+        let source: IterFactory<T> = Arc::new(move || Box::new(source()));
+        Self {
+            title,
+            all: ListModelView::new(title, source(), initial_buffer_size),
+            source,
+            filtered: None,
+            filter: None,
+            filter_input: String::new(),
+            sort: None,
+            prompt: None,
+            error: None,
+            show_table: false,
+            columns: TableColumns::new(T::COLUMNS),
+            height: initial_buffer_size,
+            changes: HashMap::new(),
+        }
+    }
+
+    /// Rebuilds the list from a new source, once the db was re-opened. The
+    /// removed entries are listed first and the changes highlighted until the
+    /// next reload. The filter, sort and selected entry are kept.
+    pub fn reload<F, I>(&mut self, source: F, changes: RowChanges<T>)
+    where
+        F: Fn() -> I + Send + Sync + 'static,
+        I: Iterator<Item = T> + Send + Sync + 'static,
+    {
+        let selected = self.all.selected_item().map(ToTableRow::identifier);
+        let source: IterFactory<T> = Arc::new(move || Box::new(source()));
+        let removed = changes.removed.len();
+        self.all = ListModelView::new(
+            self.title,
+            changes.removed.into_iter().chain(source()),
+            self.height,
+        );
+        self.source = source;
+        self.changes = changes.kinds;
+        if let Some(selected) = selected {
+            self.all.select_first_within(
+                |item| item.identifier() == selected,
+                removed + RESELECT_LOOKAHEAD,
+            );
+        }
+        self.refresh();
+    }
+
+    fn row_style(&self, item: &T) -> Option<Style> {
+        if self.changes.is_empty() {
+            return None;
+        }
+        self.changes
+            .get(&item.identifier())
+            .copied()
+            .map(diff_style)
+    }
+
+    pub fn is_prompting(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    /// The text to copy for the selected item: its identifier, or the
+    /// selected column when drawn as a table.
+    pub fn yank_text(&self) -> Option<String> {
+        let item = self.active().selected_item()?;
+        if self.show_table {
+            item.to_table_row().into_iter().nth(self.columns.selected)
+        } else {
+            Some(item.identifier())
+        }
+    }
+
+    /// Selects the first item matching the predicate. Falls back to the plain
+    /// list when the item is filtered out.
+    pub fn select_first<F>(&mut self, pred: F) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        if let Some(model) = self.filtered.as_mut() {
+            if let Some(index) = model.buffer.iter().position(&pred) {
+                model.view.select(index, model.buffer.len());
+                return true;
+            }
+            self.reset();
+        }
+        self.all.select_first(pred)
+    }
+
+    fn show_filter_bar(&self) -> bool {
+        self.prompt.is_some() || self.filter.is_some() || self.sort.is_some()
+    }
+
+    fn apply_height(&mut self) {
+        let mut height = self.height;
+        if self.show_filter_bar() {
+            height = height.saturating_sub(FILTER_BAR_HEIGHT);
+        }
+        if self.show_table {
+            // The header row
+            height = height.saturating_sub(1);
+        }
+        self.all.set_height(height);
+        if let Some(model) = self.filtered.as_mut() {
+            model.set_height(height);
+        }
+    }
+
+    /// A closure producing the items matching the current filter and sort,
+    /// which must run off the UI thread as sorting scans the whole column.
+    /// Sorted, only the first `SORT_LIMIT` items are kept. The scan stops once
+    /// the given closure reports the items are no longer wanted.
+    fn matching_items(
+        &self,
+    ) -> impl FnOnce(&dyn Fn() -> bool) -> Box<dyn Iterator<Item = T>> + Send + 'static {
+        let source = self.source.clone();
+        let filter = self.filter.clone().unwrap_or_default();
+        let sort = self.sort;
+        move |cancelled| {
+            let matching = source().filter(move |item| filter.matches(item));
+            match sort {
+                Some(sort) => Box::new(sort.top(matching, SORT_LIMIT, cancelled).into_iter()),
+                None => Box::new(matching),
+            }
+        }
+    }
+
+    /// Starts materialising the items matching the current filter and sort.
+    /// The previous job stops once its model is dropped.
+    fn refresh(&mut self) {
+        self.filtered = if self.filter.is_none() && self.sort.is_none() {
+            None
+        } else {
+            let items = self.matching_items();
+            let provider = AsyncProvider::new(move |tx| {
+                for item in items(&|| tx.is_closed()) {
+                    if tx.blocking_send(item).is_err() {
+                        break;
+                    }
+                }
+            });
+            Some(AsyncListModel::new(self.title, provider))
+        };
+        self.apply_height();
+    }
+
+    fn reset(&mut self) {
+        self.filter = None;
+        self.filter_input.clear();
+        self.sort = None;
+        self.refresh();
+    }
+
+    fn submit_filter(&mut self, input: String) {
+        let filter = match Filter::from_str(&input).and_then(|filter| {
+            filter.check_fields::<T>()?;
+            Ok(filter)
+        }) {
+            Ok(filter) => filter,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                self.prompt = Some(input);
+                return;
+            }
+        };
+        self.error = None;
+        self.filter = (!filter.is_empty()).then_some(filter);
+        self.filter_input = input;
+        self.refresh();
+    }
+
+    /// Filters the list as if `input` was submitted in the prompt.
+    pub fn set_filter(&mut self, input: String) {
+        self.prompt = None;
+        self.submit_filter(input);
+    }
+
+    /// Cycles through no sort and each of the entity fields.
+    fn cycle_sort(&mut self) {
+        let next = match self.sort {
+            None => T::FIELDS.first(),
+            Some(sort) => T::FIELDS
+                .iter()
+                .skip_while(|field| **field != sort.field)
+                .nth(1),
+        };
+        self.sort = next.map(|field| SortKey {
+            field,
+            descending: true,
+        });
+        self.refresh();
+    }
+
+    fn toggle_sort_direction(&mut self) {
+        if let Some(sort) = self.sort.as_mut() {
+            sort.descending = !sort.descending;
+            self.refresh();
+        }
+    }
+
+    fn active(&self) -> &dyn ListModel<Item = T> {
+        match &self.filtered {
+            Some(model) => model,
+            None => &self.all,
+        }
+    }
+
+    fn active_mut(&mut self) -> &mut dyn ListModel<Item = T> {
+        match &mut self.filtered {
+            Some(model) => model,
+            None => &mut self.all,
+        }
+    }
+
+    fn draw_items(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        let row_style = |item: &T| self.row_style(item);
+        match (&self.filtered, self.show_table) {
+            (Some(model), false) => model.draw_styled(f, area, is_focused, row_style),
+            (None, false) => self.all.draw_styled(f, area, is_focused, row_style),
+            (Some(model), true) => {
+                model.draw_table_styled(f, area, &self.columns, is_focused, row_style)
+            }
+            (None, true) => {
+                self.all
+                    .draw_table_styled(f, area, &self.columns, is_focused, row_style)
+            }
+        }
+    }
+
+    /// Handles the keys resizing and hiding columns while drawn as a table.
+    fn handle_table_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char(']') => self.columns.select_next(),
+            KeyCode::Char('[') => self.columns.select_prev(),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.columns.widen(),
+            KeyCode::Char('-') => self.columns.narrow(),
+            KeyCode::Char('h') => self.columns.hide_selected(),
+            KeyCode::Char('H') => self.columns.show_all(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn draw_filter_bar(&self, f: &mut Frame, area: Rect) {
+        let mut title = "Filter".to_string();
+        if let Some(sort) = self.sort {
+            title.push_str(&format!(" · sort: {} (first {})", sort, SORT_LIMIT));
+        }
+        if self
+            .filtered
+            .as_ref()
+            .is_some_and(|model| model.is_loading())
+        {
+            title.push_str(" · loading");
+        }
+
+        let mut block = Block::default().title(title).borders(Borders::ALL);
+        if self.prompt.is_some() {
+            block = block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+
+        let line = match (&self.prompt, &self.error) {
+            (Some(input), Some(error)) => Line::from(vec![
+                Span::raw(input.clone()),
+                Span::styled(format!("  {}", error), Style::default().fg(Color::Red)),
+            ]),
+            (Some(input), None) => Line::from(format!("{}_", input)),
+            (None, _) => Line::from(Span::styled(
+                self.filter_input.clone(),
+                Style::default().fg(Color::Gray),
+            )),
+        };
+        f.render_widget(Paragraph::new(line).block(block), area);
+    }
+}
+
+impl<T> ListModel for LedgerListModel<T>
+where
+    T: ToListItem + Exportable + LedgerFields + Send + Sync + 'static,
+{
+    type Item = T;
+
+    fn draw(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        if !self.show_filter_bar() {
+            self.draw_items(f, area, is_focused);
+            return;
+        }
+        let [list_area, bar_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(FILTER_BAR_HEIGHT as u16),
+            ])
+            .areas(area);
+        self.draw_items(f, list_area, is_focused);
+        self.draw_filter_bar(f, bar_area);
+    }
+
+    fn selected_item(&self) -> Option<&T> {
+        self.active().selected_item()
+    }
+
+    fn select_index_by_row(&mut self, relative_row: usize) {
+        self.active_mut().select_index_by_row(relative_row);
+    }
+
+    fn cursor_back(&mut self) {
+        self.active_mut().cursor_back();
+    }
+
+    fn cursor_next(&mut self) {
+        self.active_mut().cursor_next();
+    }
+
+    fn retreat_window(&mut self) {
+        self.active_mut().retreat_window();
+    }
+
+    fn advance_window(&mut self) {
+        self.active_mut().advance_window();
+    }
+
+    fn set_height(&mut self, height: usize) {
+        self.height = height;
+        self.apply_height();
+    }
+
+    fn poll(&mut self) {
+        if let Some(model) = self.filtered.as_mut() {
+            model.poll_data();
+        }
+    }
+
+    /// Exports the whole column, not only the loaded items, with the filter
+    /// and sort applied. Like the list, a sorted export holds the first
+    /// `SORT_LIMIT` items.
+    fn export(&self, name: String, format: ExportFormat) -> Option<ExportJob> {
+        let items = self.matching_items();
+        Some(spawn_export(name, format, move || items(&|| false)))
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> Option<Vec<Action>> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return None;
+        }
+
+        if let Some(input) = self.prompt.as_mut() {
+            match key.code {
+                // Swallowed so that the global Alt bindings, such as exports,
+                // don't fire while typing
+                _ if key.modifiers.contains(KeyModifiers::ALT) => {}
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    let input = self.prompt.take().unwrap_or_default();
+                    self.submit_filter(input);
+                }
+                KeyCode::Esc => {
+                    self.prompt = None;
+                    self.error = None;
+                    self.apply_height();
+                }
+                _ => return None,
+            }
+            // Consumed keys must yield an action, or they fall back to the
+            // global keymap (where Esc quits)
+            return Some(vec![Action::Render]);
+        }
+
+        if self.show_table && self.handle_table_key(key.code) {
+            return Some(vec![Action::Render]);
+        }
+
+        match key.code {
+            KeyCode::Char('t') => {
+                self.show_table = !self.show_table;
+                self.apply_height();
+            }
+            KeyCode::Char('/') => {
+                self.prompt = Some(self.filter_input.clone());
+                self.apply_height();
+            }
+            KeyCode::Char('s') => self.cycle_sort(),
+            KeyCode::Char('S') => self.toggle_sort_direction(),
+            KeyCode::Esc if self.filter.is_some() || self.sort.is_some() => self.reset(),
+            _ => return None,
+        }
+        Some(vec![Action::Render])
+    }
+}
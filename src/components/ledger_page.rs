@@ -1,31 +1,77 @@
 use crate::{
     components::{
-        Component, ComponentLayout, details::DetailsComponent, list::ListComponent,
-        search_bar::SearchBarComponent, search_list::SearchListComponent, tabs::TabsComponent,
+        Component, ComponentLayout,
+        async_list::AsyncListModel,
+        details::DetailsComponent,
+        ledger_list::LedgerListModel,
+        list::{ListComponent, ListModel},
+        search_bar::SearchBarComponent,
+        search_list::SearchListComponent,
+        stake_ranking::StakeRankingModel,
+        tabs::TabsComponent,
     },
     controller::{LayoutSpec, MoveFocus, find_next_focus, walk_layout},
-    model::{ledger_search::LedgerUtxoProvider, list_view::ListModelView},
-    states::{Action, ComponentId, LedgerBrowse, LedgerMode, LedgerSearch},
+    export::{Exportable, Exports},
+    model::{
+        account_details::{AccountDetails, drep_credential},
+        async_provider::AsyncProvider,
+        block_production::{PoolBlocks, load_block_production},
+        governance::{ProposalTally, committee_hot_credential, load_tallies},
+        ledger_diff::{DiffEntry, LedgerColumn, LedgerDifference, diff_ledgers},
+        ledger_filter::LedgerFields,
+        ledger_refresh::{LedgerRefresh, Refreshed, RowChanges},
+        ledger_search::{
+            AssetQuery, LedgerUtxoProvider, PaymentCredentialQuery, StakeCredentialQuery, UtxoInput,
+        },
+        ledger_state::{LedgerStateField, load_epoch_state, load_protocol_parameters},
+        list_view::ListModelView,
+        proposal::{ProposalEntry, proposal_entries},
+        stake::{PoolStake, StakeDistribution, StakeDistributionJob},
+        utxo_totals::UtxoTotals,
+    },
+    states::{Action, ComponentId, ExportFormat, LedgerBrowse, LedgerMode, LedgerSearch},
     store::owned_iter::{
-        OwnedAccountIter, OwnedBlockIssuerIter, OwnedDRepIter, OwnedPoolIter, OwnedProposalIter,
-        OwnedUtxoIter,
+        OwnedAccountIter, OwnedBlockIssuerIter, OwnedCommitteeMemberIter, OwnedDRepIter,
+        OwnedPoolIter, OwnedUtxoIter,
+    },
+    ui::{
+        to_list_item::{
+            AccountItem, BlockIssuerItem, CommitteeMemberItem, DRepItem, PoolItem, ToListItem,
+            UtxoItem,
+        },
+        to_rich::proposal::ComparableProposalIdDisplay,
+        to_table_row::ToTableRow,
     },
-    ui::to_list_item::{AccountItem, BlockIssuerItem, DRepItem, PoolItem, ProposalItem, UtxoItem},
+    view::stake_chart::draw_stake_chart,
 };
-use amaru_kernel::Address;
+use amaru_kernel::{Address, StakeCredential, network::NetworkName};
+use amaru_slot_arithmetic::EraHistory;
 use amaru_stores::rocksdb::ReadOnlyRocksDB;
-use crossterm::event::Event;
+use crossterm::event::{Event, KeyCode};
 use either::Either::{Left, Right};
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Rect},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
 };
 use std::{
     any::Any,
     collections::HashMap,
+    hash::Hash,
+    str::FromStr,
     sync::{Arc, RwLock},
 };
 use strum::IntoEnumIterator;
+use tokio::sync::mpsc::error::TryRecvError;
+use tracing::warn;
+
+const GOVERNANCE_TITLE: &str = "Governance";
+const DIFF_TITLE: &str = "Differences";
+/// Differences listed at most, a diff against an old snapshot could cover
+/// most of the UTXO set.
+const MAX_DIFFERENCES: usize = 10_000;
 
 pub struct LedgerPageComponent {
     id: ComponentId,
@@ -33,13 +79,24 @@ pub struct LedgerPageComponent {
     // Tabs
     mode_tabs: TabsComponent<LedgerMode>,
     // Details
-    account_details: DetailsComponent<AccountItem>,
+    account_details: DetailsComponent<AccountDetails>,
     block_details: DetailsComponent<BlockIssuerItem>,
+    block_production_details: DetailsComponent<PoolBlocks>,
+    committee_details: DetailsComponent<CommitteeMemberItem>,
     drep_details: DetailsComponent<DRepItem>,
     pool_details: DetailsComponent<PoolItem>,
-    proposal_details: DetailsComponent<ProposalItem>,
+    proposal_details: DetailsComponent<ProposalEntry>,
     utxo_details: DetailsComponent<UtxoItem>,
     utxo_by_addr_details: DetailsComponent<UtxoItem>,
+    utxo_by_input_details: DetailsComponent<UtxoItem>,
+    utxo_by_stake_details: DetailsComponent<UtxoItem>,
+    utxo_by_payment_details: DetailsComponent<UtxoItem>,
+    utxo_by_asset_details: DetailsComponent<UtxoItem>,
+    protocol_param_details: DetailsComponent<LedgerStateField>,
+    epoch_state_details: DetailsComponent<LedgerStateField>,
+    governance_details: DetailsComponent<ProposalTally>,
+    stake_details: DetailsComponent<PoolStake>,
+    diff_details: DetailsComponent<DiffEntry>,
 
     // Lists
     // Options
@@ -47,26 +104,66 @@ pub struct LedgerPageComponent {
     search_options: ListComponent<ListModelView<LedgerSearch>>,
 
     // Content
-    accounts_list: ListComponent<ListModelView<AccountItem>>,
-    block_issuers_list: ListComponent<ListModelView<BlockIssuerItem>>,
-    dreps_list: ListComponent<ListModelView<DRepItem>>,
-    pools_list: ListComponent<ListModelView<PoolItem>>,
-    proposals_list: ListComponent<ListModelView<ProposalItem>>,
-    utxos_list: ListComponent<ListModelView<UtxoItem>>,
+    accounts_list: ListComponent<LedgerListModel<AccountItem>>,
+    block_issuers_list: ListComponent<LedgerListModel<BlockIssuerItem>>,
+    block_production_list: ListComponent<AsyncListModel<PoolBlocks>>,
+    committee_list: ListComponent<LedgerListModel<CommitteeMemberItem>>,
+    dreps_list: ListComponent<LedgerListModel<DRepItem>>,
+    pools_list: ListComponent<LedgerListModel<PoolItem>>,
+    proposals_list: ListComponent<LedgerListModel<ProposalEntry>>,
+    utxos_list: ListComponent<LedgerListModel<UtxoItem>>,
+    protocol_params_list: ListComponent<ListModelView<LedgerStateField>>,
+    epoch_state_list: ListComponent<ListModelView<LedgerStateField>>,
+    governance_list: ListComponent<AsyncListModel<ProposalTally>>,
+    stake_list: ListComponent<StakeRankingModel>,
+    // Aggregated once, when the governance or stake list is first shown
+    stake: StakeDistributionJob,
+    diff_list: ListComponent<AsyncListModel<LedgerDifference>>,
 
     // Search
     search_bar: SearchBarComponent,
     utxos_by_addr_list: SearchListComponent<Address, UtxoItem>,
+    utxo_by_input_list: SearchListComponent<UtxoInput, UtxoItem>,
+    utxos_by_stake_list: SearchListComponent<StakeCredentialQuery, UtxoItem>,
+    utxos_by_payment_list: SearchListComponent<PaymentCredentialQuery, UtxoItem>,
+    utxos_by_asset_list: SearchListComponent<AssetQuery, UtxoItem>,
+
+    // Aggregates of the active search results
+    utxo_totals: UtxoTotals,
+    utxo_totals_details: DetailsComponent<UtxoTotals>,
+
+    // Shown with the refresh status
+    exports: Exports,
 
+    // The selected account joined with its delegations, refreshed on tick
+    selected_account: Option<AccountDetails>,
+    // Loads the delegations of a newly selected account in the background
+    account_job: Option<(StakeCredential, AsyncProvider<AccountDetails>)>,
+
+    // Read again when the db is refreshed
+    protocol_params: Vec<LedgerStateField>,
+    changed_params_only: bool,
+
+    db: Arc<ReadOnlyRocksDB>,
+    compare_db: Option<Arc<ReadOnlyRocksDB>>,
+    refresh: LedgerRefresh,
+    network: NetworkName,
     last_layout: RwLock<ComponentLayout>,
     active_focus: RwLock<ComponentId>,
 }
 
 impl LedgerPageComponent {
-    pub fn new(db: Arc<ReadOnlyRocksDB>) -> Self {
+    pub fn new(
+        db: Arc<ReadOnlyRocksDB>,
+        compare_db: Option<Arc<ReadOnlyRocksDB>>,
+        refresh: LedgerRefresh,
+        network: NetworkName,
+    ) -> Self {
         let list_height = 0; // Will be updated in render
         let options_height = 0;
 
+        let (protocol_params, epoch_state) = Self::load_ledger_state(&db, network);
+
         Self {
             id: ComponentId::LedgerPage,
             mode_tabs: TabsComponent::new(ComponentId::LedgerModeTabs, true),
@@ -80,6 +177,14 @@ impl LedgerPageComponent {
                 ComponentId::LedgerBlockIssuerDetails,
                 "Block Issuer Details",
             ),
+            block_production_details: DetailsComponent::new(
+                ComponentId::LedgerBlockProductionDetails,
+                "Pool Block Production",
+            ),
+            committee_details: DetailsComponent::new(
+                ComponentId::LedgerCommitteeDetails,
+                "Committee Member Details",
+            ),
             drep_details: DetailsComponent::new(ComponentId::LedgerDRepDetails, "DRep Details"),
             pool_details: DetailsComponent::new(ComponentId::LedgerPoolDetails, "Pool Details"),
             proposal_details: DetailsComponent::new(
@@ -91,6 +196,36 @@ impl LedgerPageComponent {
                 ComponentId::LedgerUtxosByAddrDetails,
                 "UTXO Details",
             ),
+            utxo_by_input_details: DetailsComponent::new(
+                ComponentId::LedgerUtxoByInputDetails,
+                "UTXO Details",
+            ),
+            utxo_by_stake_details: DetailsComponent::new(
+                ComponentId::LedgerUtxosByStakeDetails,
+                "UTXO Details",
+            ),
+            utxo_by_payment_details: DetailsComponent::new(
+                ComponentId::LedgerUtxosByPaymentDetails,
+                "UTXO Details",
+            ),
+            utxo_by_asset_details: DetailsComponent::new(
+                ComponentId::LedgerUtxosByAssetDetails,
+                "UTXO Details",
+            ),
+            protocol_param_details: DetailsComponent::new(
+                ComponentId::LedgerProtocolParamDetails,
+                "Protocol Parameter Details",
+            ),
+            epoch_state_details: DetailsComponent::new(
+                ComponentId::LedgerEpochStateDetails,
+                "Epoch State Details",
+            ),
+            governance_details: DetailsComponent::new(
+                ComponentId::LedgerGovernanceDetails,
+                "Proposal Tally",
+            ),
+            stake_details: DetailsComponent::new(ComponentId::LedgerStakeDetails, "Pool Stake"),
+            diff_details: DetailsComponent::new(ComponentId::LedgerDiffDetails, "Difference"),
 
             // Options
             browse_options: ListComponent::new(
@@ -105,31 +240,105 @@ impl LedgerPageComponent {
             // Lists
             accounts_list: ListComponent::new(
                 ComponentId::LedgerAccountsList,
-                ListModelView::new("Accounts", OwnedAccountIter::new(db.clone()), list_height),
+                LedgerListModel::new(
+                    "Accounts",
+                    {
+                        let db = db.clone();
+                        move || OwnedAccountIter::new(db.clone())
+                    },
+                    list_height,
+                ),
             ),
             block_issuers_list: ListComponent::new(
                 ComponentId::LedgerBlockIssuersList,
-                ListModelView::new(
+                LedgerListModel::new(
                     "Block Issuers",
-                    OwnedBlockIssuerIter::new(db.clone()),
+                    {
+                        let db = db.clone();
+                        move || OwnedBlockIssuerIter::new(db.clone())
+                    },
+                    list_height,
+                ),
+            ),
+            block_production_list: ListComponent::new(
+                ComponentId::LedgerBlockProductionList,
+                Self::block_production_model(db.clone(), network),
+            ),
+            committee_list: ListComponent::new(
+                ComponentId::LedgerCommitteeList,
+                LedgerListModel::new(
+                    "Committee",
+                    {
+                        let db = db.clone();
+                        move || OwnedCommitteeMemberIter::new(db.clone())
+                    },
                     list_height,
                 ),
             ),
             dreps_list: ListComponent::new(
                 ComponentId::LedgerDRepsList,
-                ListModelView::new("DReps", OwnedDRepIter::new(db.clone()), list_height),
+                LedgerListModel::new(
+                    "DReps",
+                    {
+                        let db = db.clone();
+                        move || OwnedDRepIter::new(db.clone())
+                    },
+                    list_height,
+                ),
             ),
             pools_list: ListComponent::new(
                 ComponentId::LedgerPoolsList,
-                ListModelView::new("Pools", OwnedPoolIter::new(db.clone()), list_height),
+                LedgerListModel::new(
+                    "Pools",
+                    {
+                        let db = db.clone();
+                        move || OwnedPoolIter::new(db.clone())
+                    },
+                    list_height,
+                ),
             ),
             proposals_list: ListComponent::new(
                 ComponentId::LedgerProposalsList,
-                ListModelView::new("Proposals", OwnedProposalIter::new(db.clone()), list_height),
+                LedgerListModel::new(
+                    "Proposals",
+                    {
+                        let db = db.clone();
+                        move || proposal_entries(db.clone(), network)
+                    },
+                    list_height,
+                ),
             ),
             utxos_list: ListComponent::new(
                 ComponentId::LedgerUtxosList,
-                ListModelView::new("Utxos", OwnedUtxoIter::new(db.clone()), list_height),
+                LedgerListModel::new(
+                    "Utxos",
+                    {
+                        let db = db.clone();
+                        move || OwnedUtxoIter::new(db.clone())
+                    },
+                    list_height,
+                ),
+            ),
+            protocol_params_list: ListComponent::new(
+                ComponentId::LedgerProtocolParamsList,
+                Self::protocol_params_model(&protocol_params, false, list_height),
+            ),
+            epoch_state_list: ListComponent::new(
+                ComponentId::LedgerEpochStateList,
+                ListModelView::new("Epoch State", epoch_state.into_iter(), list_height),
+            ),
+            governance_list: ListComponent::new(
+                ComponentId::LedgerGovernanceList,
+                AsyncListModel::waiting(GOVERNANCE_TITLE),
+            ),
+            stake_list: ListComponent::new(
+                ComponentId::LedgerStakeList,
+                StakeRankingModel::new("Pools by Stake", db.clone()),
+            ),
+            stake: StakeDistributionJob::new(db.clone()),
+            diff_list: ListComponent::new(
+                ComponentId::LedgerDiffList,
+                AsyncListModel::waiting(DIFF_TITLE),
             ),
 
             // Search
@@ -139,12 +348,291 @@ impl LedgerPageComponent {
                 "Utxos by Address",
                 Box::new(LedgerUtxoProvider { db: db.clone() }),
             ),
+            utxo_by_input_list: SearchListComponent::new(
+                ComponentId::LedgerUtxoByInputList,
+                "Utxo by Input",
+                Box::new(LedgerUtxoProvider { db: db.clone() }),
+            ),
+            utxos_by_stake_list: SearchListComponent::new(
+                ComponentId::LedgerUtxosByStakeList,
+                "Utxos by Stake Credential",
+                Box::new(LedgerUtxoProvider { db: db.clone() }),
+            ),
+            utxos_by_payment_list: SearchListComponent::new(
+                ComponentId::LedgerUtxosByPaymentList,
+                "Utxos by Payment Credential",
+                Box::new(LedgerUtxoProvider { db: db.clone() }),
+            ),
+            utxos_by_asset_list: SearchListComponent::new(
+                ComponentId::LedgerUtxosByAssetList,
+                "Utxos by Asset",
+                Box::new(LedgerUtxoProvider { db: db.clone() }),
+            ),
+
+            utxo_totals: UtxoTotals::default(),
+            utxo_totals_details: DetailsComponent::new(ComponentId::LedgerUtxoTotals, "Totals"),
+
+            exports: Exports::default(),
+            selected_account: None,
+            account_job: None,
 
+            protocol_params,
+            changed_params_only: false,
+
+            db,
+            compare_db,
+            refresh,
+            network,
             last_layout: RwLock::new(ComponentLayout::new()),
             active_focus: RwLock::new(ComponentId::LedgerBrowseOptions),
         }
     }
 
+    fn load_ledger_state(
+        db: &ReadOnlyRocksDB,
+        network: NetworkName,
+    ) -> (Vec<LedgerStateField>, Vec<LedgerStateField>) {
+        let protocol_params = load_protocol_parameters(db, &network).unwrap_or_else(|e| {
+            warn!("Failed to read the protocol parameters: {}", e);
+            Vec::new()
+        });
+        let epoch_state = match load_epoch_state(db) {
+            Ok(state) => state.fields(),
+            Err(e) => {
+                warn!("Failed to read the epoch state: {}", e);
+                Vec::new()
+            }
+        };
+        (protocol_params, epoch_state)
+    }
+
+    fn list_height(&self, id: ComponentId) -> usize {
+        self.last_layout
+            .read()
+            .unwrap()
+            .get(&id)
+            .map_or(0, |area| area.height as usize)
+    }
+
+    fn reload_list<T, I>(
+        list: &mut LedgerListModel<T>,
+        db: &Arc<ReadOnlyRocksDB>,
+        iter: fn(Arc<ReadOnlyRocksDB>) -> I,
+        changes: RowChanges<T>,
+    ) where
+        T: ToListItem + Exportable + LedgerFields + Send + Sync + 'static,
+        I: Iterator<Item = T> + Send + Sync + 'static,
+    {
+        let db = db.clone();
+        list.reload(move || iter(db.clone()), changes);
+    }
+
+    /// Swaps in the re-opened db, rebuilding everything read from it. Browse
+    /// lists keep their filter and selection and highlight what changed.
+    fn apply_refresh(&mut self, refreshed: Refreshed) {
+        let Refreshed { db, mut changes } = refreshed;
+
+        // Browse lists
+        Self::reload_list(
+            &mut self.accounts_list.model,
+            &db,
+            OwnedAccountIter::new,
+            changes.take_rows(),
+        );
+        Self::reload_list(
+            &mut self.block_issuers_list.model,
+            &db,
+            OwnedBlockIssuerIter::new,
+            changes.take_rows(),
+        );
+        Self::reload_list(
+            &mut self.committee_list.model,
+            &db,
+            OwnedCommitteeMemberIter::new,
+            changes.take_rows(),
+        );
+        Self::reload_list(
+            &mut self.dreps_list.model,
+            &db,
+            OwnedDRepIter::new,
+            changes.take_rows(),
+        );
+        Self::reload_list(
+            &mut self.pools_list.model,
+            &db,
+            OwnedPoolIter::new,
+            changes.take_rows(),
+        );
+        let (proposals_db, network) = (db.clone(), self.network);
+        self.proposals_list.model.reload(
+            move || proposal_entries(proposals_db.clone(), network),
+            changes.take_rows(),
+        );
+        Self::reload_list(
+            &mut self.utxos_list.model,
+            &db,
+            OwnedUtxoIter::new,
+            changes.take_rows(),
+        );
+
+        // Ledger state
+        let (protocol_params, epoch_state) = Self::load_ledger_state(&db, self.network);
+        self.protocol_params = protocol_params;
+        self.protocol_params_list.model = Self::protocol_params_model(
+            &self.protocol_params,
+            self.changed_params_only,
+            self.list_height(ComponentId::LedgerProtocolParamsList),
+        );
+        self.epoch_state_list.model = ListModelView::new(
+            "Epoch State",
+            epoch_state.into_iter(),
+            self.list_height(ComponentId::LedgerEpochStateList),
+        );
+
+        // Aggregates, computed again in the background
+        self.governance_list.model = AsyncListModel::waiting(GOVERNANCE_TITLE);
+        self.block_production_list.model = Self::block_production_model(db.clone(), self.network);
+        self.stake_list.model = StakeRankingModel::new("Pools by Stake", db.clone());
+        // Dropping the job stops its scan, it starts again once needed
+        self.stake = StakeDistributionJob::new(db.clone());
+        if self.compare_db.is_some() {
+            self.diff_list.model = AsyncListModel::waiting(DIFF_TITLE);
+        }
+
+        // Searches run again on the new db
+        self.utxos_by_addr_list
+            .set_provider(Box::new(LedgerUtxoProvider { db: db.clone() }));
+        self.utxo_by_input_list
+            .set_provider(Box::new(LedgerUtxoProvider { db: db.clone() }));
+        self.utxos_by_stake_list
+            .set_provider(Box::new(LedgerUtxoProvider { db: db.clone() }));
+        self.utxos_by_payment_list
+            .set_provider(Box::new(LedgerUtxoProvider { db: db.clone() }));
+        self.utxos_by_asset_list
+            .set_provider(Box::new(LedgerUtxoProvider { db: db.clone() }));
+
+        self.selected_account = None;
+        self.account_job = None;
+        self.db = db;
+    }
+
+    fn draw_refresh_status(&self, frame: &mut Frame, area: Rect) {
+        let color = if self.refresh.is_refreshing() {
+            Color::Yellow
+        } else {
+            Color::Gray
+        };
+        let mut block = Block::default().title("Ledger db").borders(Borders::ALL);
+        match self.exports.status() {
+            Some(Ok(status)) => block = block.title_bottom(status),
+            Some(Err(status)) => {
+                block = block.title_bottom(Line::styled(status, Style::default().fg(Color::Red)))
+            }
+            None => {}
+        }
+        let widget = Paragraph::new(self.refresh.status())
+            .style(Style::default().fg(color))
+            .block(block);
+        frame.render_widget(widget, area);
+    }
+
+    /// Groups the block issuers by pool and epoch in the background.
+    fn block_production_model(
+        db: Arc<ReadOnlyRocksDB>,
+        network: NetworkName,
+    ) -> AsyncListModel<PoolBlocks> {
+        let era_history: &'static EraHistory = network.into();
+        let provider =
+            AsyncProvider::new(move |tx| match load_block_production(&db, era_history) {
+                Ok(pools) => {
+                    for pool in pools {
+                        if tx.blocking_send(pool).is_err() {
+                            break;
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to group the block issuers: {}", e),
+            });
+        AsyncListModel::new("Block Production", provider)
+    }
+
+    /// Shows the slots issued by the selected pool of the block production
+    /// summary, by filtering the block issuers list on it. Returns the list to
+    /// focus.
+    fn drill_into_block_production(&mut self) -> Option<ComponentId> {
+        let pool_id = self.block_production_list.model.selected_item()?.pool_id;
+        self.block_issuers_list
+            .model
+            .set_filter(format!("pool={}", pool_id));
+        self.browse_options
+            .model
+            .select_first(|b| *b == LedgerBrowse::BlockIssuers);
+        Some(ComponentId::LedgerBlockIssuersList)
+    }
+
+    /// Tallies the proposals against the stake distribution.
+    fn governance_model(
+        db: Arc<ReadOnlyRocksDB>,
+        stake: Arc<StakeDistribution>,
+    ) -> AsyncListModel<ProposalTally> {
+        let provider = AsyncProvider::new(move |tx| {
+            if let Err(e) = load_tallies(&db, &stake, |tally| tx.blocking_send(tally).is_ok()) {
+                warn!("Failed to tally the proposals: {}", e);
+            }
+        });
+        AsyncListModel::new(GOVERNANCE_TITLE, provider)
+    }
+
+    /// Streams the first differences between the ledger and the compared
+    /// one, if any was given.
+    fn diff_model(
+        db: Arc<ReadOnlyRocksDB>,
+        compare_db: Option<Arc<ReadOnlyRocksDB>>,
+    ) -> AsyncListModel<LedgerDifference> {
+        let title = if compare_db.is_some() {
+            DIFF_TITLE
+        } else {
+            "Differences (no --compare-ledger-db)"
+        };
+        let provider = AsyncProvider::new(move |tx| {
+            let Some(compare_db) = compare_db else {
+                return;
+            };
+            let columns: Vec<_> = LedgerColumn::iter().collect();
+            if let Err(e) = diff_ledgers(&db, &compare_db, &columns, |difference| {
+                tx.blocking_send(difference).is_ok()
+            }) {
+                warn!("Failed to diff the ledgers: {}", e);
+            }
+        });
+        AsyncListModel::new(title, provider).with_limit(MAX_DIFFERENCES)
+    }
+
+    fn protocol_params_model(
+        params: &[LedgerStateField],
+        changed_only: bool,
+        height: usize,
+    ) -> ListModelView<LedgerStateField> {
+        let (title, params): (_, Vec<_>) = if changed_only {
+            (
+                "Protocol Parameters (changed)",
+                params.iter().filter(|p| p.changed()).cloned().collect(),
+            )
+        } else {
+            ("Protocol Parameters", params.to_vec())
+        };
+        ListModelView::new(title, params.into_iter(), height)
+    }
+
+    /// Switches the protocol parameters list between all the parameters and
+    /// the ones that differ from the network's initial parameters.
+    fn toggle_changed_params(&mut self) {
+        self.changed_params_only = !self.changed_params_only;
+        let height = self.list_height(ComponentId::LedgerProtocolParamsList);
+        self.protocol_params_list.model =
+            Self::protocol_params_model(&self.protocol_params, self.changed_params_only, height);
+    }
+
     fn dispatch_to_child(&mut self, id: ComponentId, event: &Event, area: Rect) -> Vec<Action> {
         match id {
             // Mode tabs
@@ -162,22 +650,72 @@ impl LedgerPageComponent {
             ComponentId::LedgerBlockIssuersList => {
                 self.block_issuers_list.handle_event(event, area)
             }
+            ComponentId::LedgerBlockProductionList => {
+                self.block_production_list.handle_event(event, area)
+            }
+            ComponentId::LedgerCommitteeList => self.committee_list.handle_event(event, area),
             ComponentId::LedgerDRepsList => self.dreps_list.handle_event(event, area),
             ComponentId::LedgerPoolsList => self.pools_list.handle_event(event, area),
             ComponentId::LedgerProposalsList => self.proposals_list.handle_event(event, area),
             ComponentId::LedgerUtxosList => self.utxos_list.handle_event(event, area),
+            ComponentId::LedgerProtocolParamsList => {
+                self.protocol_params_list.handle_event(event, area)
+            }
+            ComponentId::LedgerEpochStateList => self.epoch_state_list.handle_event(event, area),
+            ComponentId::LedgerGovernanceList => self.governance_list.handle_event(event, area),
+            ComponentId::LedgerStakeList => self.stake_list.handle_event(event, area),
+            ComponentId::LedgerDiffList => self.diff_list.handle_event(event, area),
             ComponentId::LedgerUtxosByAddrList => self.utxos_by_addr_list.handle_event(event, area),
+            ComponentId::LedgerUtxoByInputList => self.utxo_by_input_list.handle_event(event, area),
+            ComponentId::LedgerUtxosByStakeList => {
+                self.utxos_by_stake_list.handle_event(event, area)
+            }
+            ComponentId::LedgerUtxosByPaymentList => {
+                self.utxos_by_payment_list.handle_event(event, area)
+            }
+            ComponentId::LedgerUtxosByAssetList => {
+                self.utxos_by_asset_list.handle_event(event, area)
+            }
 
             // Details
             ComponentId::LedgerAccountDetails => self.account_details.handle_event(event, area),
             ComponentId::LedgerBlockIssuerDetails => self.block_details.handle_event(event, area),
+            ComponentId::LedgerBlockProductionDetails => {
+                self.block_production_details.handle_event(event, area)
+            }
+            ComponentId::LedgerCommitteeDetails => self.committee_details.handle_event(event, area),
             ComponentId::LedgerDRepDetails => self.drep_details.handle_event(event, area),
             ComponentId::LedgerPoolDetails => self.pool_details.handle_event(event, area),
             ComponentId::LedgerProposalDetails => self.proposal_details.handle_event(event, area),
             ComponentId::LedgerUtxoDetails => self.utxo_details.handle_event(event, area),
+            ComponentId::LedgerProtocolParamDetails => {
+                self.protocol_param_details.handle_event(event, area)
+            }
+            ComponentId::LedgerEpochStateDetails => {
+                self.epoch_state_details.handle_event(event, area)
+            }
+            ComponentId::LedgerGovernanceDetails => {
+                self.governance_details.handle_event(event, area)
+            }
+            ComponentId::LedgerStakeDetails => self.stake_details.handle_event(event, area),
+            ComponentId::LedgerDiffDetails => self.diff_details.handle_event(event, area),
             ComponentId::LedgerUtxosByAddrDetails => {
                 self.utxo_by_addr_details.handle_event(event, area)
             }
+            ComponentId::LedgerUtxoByInputDetails => {
+                self.utxo_by_input_details.handle_event(event, area)
+            }
+            ComponentId::LedgerUtxosByStakeDetails => {
+                self.utxo_by_stake_details.handle_event(event, area)
+            }
+            ComponentId::LedgerUtxosByPaymentDetails => {
+                self.utxo_by_payment_details.handle_event(event, area)
+            }
+            ComponentId::LedgerUtxosByAssetDetails => {
+                self.utxo_by_asset_details.handle_event(event, area)
+            }
+
+            ComponentId::LedgerUtxoTotals => self.utxo_totals_details.handle_event(event, area),
 
             // Default
             _ => Vec::new(),
@@ -189,22 +727,351 @@ impl LedgerPageComponent {
         match self.browse_options.model.selected_item() {
             Some(LedgerBrowse::Accounts) => ComponentId::LedgerAccountsList,
             Some(LedgerBrowse::BlockIssuers) => ComponentId::LedgerBlockIssuersList,
+            Some(LedgerBrowse::BlockProduction) => ComponentId::LedgerBlockProductionList,
+            Some(LedgerBrowse::Committee) => ComponentId::LedgerCommitteeList,
             Some(LedgerBrowse::DReps) => ComponentId::LedgerDRepsList,
             Some(LedgerBrowse::Pools) => ComponentId::LedgerPoolsList,
             Some(LedgerBrowse::Proposals) => ComponentId::LedgerProposalsList,
             Some(LedgerBrowse::Utxos) => ComponentId::LedgerUtxosList,
+            Some(LedgerBrowse::ProtocolParameters) => ComponentId::LedgerProtocolParamsList,
+            Some(LedgerBrowse::EpochState) => ComponentId::LedgerEpochStateList,
+            Some(LedgerBrowse::Governance) => ComponentId::LedgerGovernanceList,
+            Some(LedgerBrowse::StakeDistribution) => ComponentId::LedgerStakeList,
             None => ComponentId::LedgerAccountsList,
         }
     }
 
+    /// The ledger column a browse list shows, if any.
+    fn list_column(list_id: ComponentId) -> Option<LedgerColumn> {
+        match list_id {
+            ComponentId::LedgerAccountsList => Some(LedgerColumn::Accounts),
+            ComponentId::LedgerBlockIssuersList => Some(LedgerColumn::BlockIssuers),
+            ComponentId::LedgerCommitteeList => Some(LedgerColumn::Committee),
+            ComponentId::LedgerDRepsList => Some(LedgerColumn::DReps),
+            ComponentId::LedgerPoolsList => Some(LedgerColumn::Pools),
+            ComponentId::LedgerProposalsList => Some(LedgerColumn::Proposals),
+            ComponentId::LedgerUtxosList => Some(LedgerColumn::Utxos),
+            _ => None,
+        }
+    }
+
+    // Helper to determine which search list and details are currently active
+    fn get_active_search_component_ids(&self) -> (ComponentId, ComponentId) {
+        match self.search_options.model.selected_item() {
+            Some(LedgerSearch::UtxosByAddress) | None => (
+                ComponentId::LedgerUtxosByAddrList,
+                ComponentId::LedgerUtxosByAddrDetails,
+            ),
+            Some(LedgerSearch::UtxoByInput) => (
+                ComponentId::LedgerUtxoByInputList,
+                ComponentId::LedgerUtxoByInputDetails,
+            ),
+            Some(LedgerSearch::UtxosByStakeCredential) => (
+                ComponentId::LedgerUtxosByStakeList,
+                ComponentId::LedgerUtxosByStakeDetails,
+            ),
+            Some(LedgerSearch::UtxosByPaymentCredential) => (
+                ComponentId::LedgerUtxosByPaymentList,
+                ComponentId::LedgerUtxosByPaymentDetails,
+            ),
+            Some(LedgerSearch::UtxosByAsset) => (
+                ComponentId::LedgerUtxosByAssetList,
+                ComponentId::LedgerUtxosByAssetDetails,
+            ),
+        }
+    }
+
+    fn search_results<Q>(
+        list: &SearchListComponent<Q, UtxoItem>,
+    ) -> Option<(usize, &[UtxoItem], bool)>
+    where
+        Q: Clone + Eq + Hash + FromStr + Send + Sync + 'static,
+    {
+        list.current_results().map(|model| {
+            (
+                list.generation(),
+                model.buffer.as_slice(),
+                model.is_loading(),
+            )
+        })
+    }
+
+    /// Folds the newly arrived results of the active search into the totals.
+    fn update_utxo_totals(&mut self) {
+        let (list_id, _) = self.get_active_search_component_ids();
+        let results = match self.search_options.model.selected_item() {
+            Some(LedgerSearch::UtxosByAddress) | None => {
+                Self::search_results(&self.utxos_by_addr_list)
+            }
+            Some(LedgerSearch::UtxoByInput) => Self::search_results(&self.utxo_by_input_list),
+            Some(LedgerSearch::UtxosByStakeCredential) => {
+                Self::search_results(&self.utxos_by_stake_list)
+            }
+            Some(LedgerSearch::UtxosByPaymentCredential) => {
+                Self::search_results(&self.utxos_by_payment_list)
+            }
+            Some(LedgerSearch::UtxosByAsset) => Self::search_results(&self.utxos_by_asset_list),
+        };
+        match results {
+            Some((generation, items, is_loading)) => {
+                self.utxo_totals
+                    .update((list_id, generation), items, is_loading)
+            }
+            None => self.utxo_totals = UtxoTotals::default(),
+        }
+    }
+
+    /// Reloads the delegations of the selected account when it changed, in
+    /// the background as finding its DRep scans them all.
+    fn update_selected_account(&mut self) {
+        if let Some((_, job)) = self.account_job.as_mut() {
+            match job.rx.try_recv() {
+                Ok(details) => {
+                    self.selected_account = Some(details);
+                    self.account_job = None;
+                }
+                Err(TryRecvError::Empty) => {}
+                Err(TryRecvError::Disconnected) => self.account_job = None,
+            }
+        }
+
+        let selected = self.accounts_list.model.selected_item();
+        let shown = match &self.account_job {
+            Some((credential, _)) => Some(credential),
+            None => self.selected_account.as_ref().map(|a| a.credential()),
+        };
+        if selected.map(|(credential, _)| credential) == shown {
+            return;
+        }
+        self.selected_account = None;
+        self.account_job = selected.map(|account| {
+            let db = self.db.clone();
+            let account = account.clone();
+            let credential = account.0.clone();
+            let job = AsyncProvider::new(move |tx| {
+                let _ = tx.blocking_send(AccountDetails::load(&db, &account));
+            });
+            (credential, job)
+        });
+    }
+
+    /// Shows the pool, or DRep, the selected account delegates to in its
+    /// browse list. Returns the list to focus on success.
+    fn jump_from_account(&mut self, target: LedgerBrowse) -> Option<ComponentId> {
+        let account = self.selected_account.as_ref()?;
+        let found = match target {
+            LedgerBrowse::Pools => {
+                let (pool_id, _) = account.pool.as_ref()?;
+                let pool_id = *pool_id;
+                self.pools_list.model.select_first(|(id, _)| *id == pool_id)
+            }
+            LedgerBrowse::DReps => {
+                let credential = drep_credential(&account.drep.as_ref()?.0)?;
+                self.dreps_list
+                    .model
+                    .select_first(|(key, _)| *key == credential)
+            }
+            _ => false,
+        };
+        if !found {
+            warn!("{:?} delegated to by the account was not found", target);
+            return None;
+        }
+
+        self.browse_options.model.select_first(|b| *b == target);
+        Some(self.get_active_list_component_id())
+    }
+
+    /// Shows, in the committee list, the first committee member that voted on
+    /// the selected proposal. Returns the list to focus on success.
+    fn jump_to_committee_member(&mut self) -> Option<ComponentId> {
+        let hot_credentials: Vec<_> = self
+            .governance_list
+            .model
+            .selected_item()?
+            .votes
+            .iter()
+            .filter_map(|(voter, _)| committee_hot_credential(voter))
+            .collect();
+        if hot_credentials.is_empty() {
+            warn!("No committee member voted on the proposal");
+            return None;
+        }
+
+        let found = self.committee_list.model.select_first(|(_, row)| {
+            row.hot_credential
+                .as_ref()
+                .is_some_and(|hot| hot_credentials.contains(hot))
+        });
+        if !found {
+            warn!("The committee members that voted were not found");
+            return None;
+        }
+
+        self.browse_options
+            .model
+            .select_first(|b| *b == LedgerBrowse::Committee);
+        Some(ComponentId::LedgerCommitteeList)
+    }
+
+    fn is_prompting(&self, focus: ComponentId) -> bool {
+        match focus {
+            ComponentId::LedgerAccountsList => self.accounts_list.model.is_prompting(),
+            ComponentId::LedgerBlockIssuersList => self.block_issuers_list.model.is_prompting(),
+            ComponentId::LedgerCommitteeList => self.committee_list.model.is_prompting(),
+            ComponentId::LedgerDRepsList => self.dreps_list.model.is_prompting(),
+            ComponentId::LedgerPoolsList => self.pools_list.model.is_prompting(),
+            ComponentId::LedgerProposalsList => self.proposals_list.model.is_prompting(),
+            ComponentId::LedgerUtxosList => self.utxos_list.model.is_prompting(),
+            _ => false,
+        }
+    }
+
+    /// The text `y` copies from the focused component: the identifier of the
+    /// selected item, or the selected column of a list drawn as a table.
+    fn yank_text(&self, focus: ComponentId) -> Option<String> {
+        match focus {
+            // Browse lists
+            ComponentId::LedgerAccountsList => self.accounts_list.model.yank_text(),
+            ComponentId::LedgerBlockIssuersList => self.block_issuers_list.model.yank_text(),
+            ComponentId::LedgerCommitteeList => self.committee_list.model.yank_text(),
+            ComponentId::LedgerDRepsList => self.dreps_list.model.yank_text(),
+            ComponentId::LedgerPoolsList => self.pools_list.model.yank_text(),
+            ComponentId::LedgerProposalsList => self.proposals_list.model.yank_text(),
+            ComponentId::LedgerUtxosList => self.utxos_list.model.yank_text(),
+
+            // Details
+            ComponentId::LedgerAccountDetails => self
+                .selected_account
+                .as_ref()
+                .map(|a| a.account.identifier()),
+            ComponentId::LedgerBlockIssuerDetails => self
+                .block_issuers_list
+                .model
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerCommitteeDetails => self
+                .committee_list
+                .model
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerDRepDetails => self
+                .dreps_list
+                .model
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerPoolDetails => self
+                .pools_list
+                .model
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerProposalDetails => self
+                .proposals_list
+                .model
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerUtxoDetails => self
+                .utxos_list
+                .model
+                .selected_item()
+                .map(ToTableRow::identifier),
+
+            // Ledger state, copied as a whole value
+            ComponentId::LedgerProtocolParamsList | ComponentId::LedgerProtocolParamDetails => self
+                .protocol_params_list
+                .model
+                .selected_item()
+                .map(|field| field.value.join("\n")),
+            ComponentId::LedgerEpochStateList | ComponentId::LedgerEpochStateDetails => self
+                .epoch_state_list
+                .model
+                .selected_item()
+                .map(|field| field.value.join("\n")),
+            ComponentId::LedgerGovernanceList | ComponentId::LedgerGovernanceDetails => self
+                .governance_list
+                .model
+                .selected_item()
+                .map(|tally| ComparableProposalIdDisplay(tally.id()).to_string()),
+            ComponentId::LedgerBlockProductionList | ComponentId::LedgerBlockProductionDetails => {
+                self.block_production_list
+                    .model
+                    .selected_item()
+                    .map(|pool| pool.pool_id.to_string())
+            }
+            ComponentId::LedgerStakeList | ComponentId::LedgerStakeDetails => self
+                .stake_list
+                .model
+                .selected_item()
+                .map(|pool| pool.pool_id.to_string()),
+            ComponentId::LedgerDiffList | ComponentId::LedgerDiffDetails => self
+                .diff_list
+                .model
+                .selected_item()
+                .map(LedgerDifference::identifier),
+
+            // Search results and their details
+            ComponentId::LedgerUtxosByAddrList | ComponentId::LedgerUtxosByAddrDetails => self
+                .utxos_by_addr_list
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerUtxoByInputList | ComponentId::LedgerUtxoByInputDetails => self
+                .utxo_by_input_list
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerUtxosByStakeList | ComponentId::LedgerUtxosByStakeDetails => self
+                .utxos_by_stake_list
+                .selected_item()
+                .map(ToTableRow::identifier),
+            ComponentId::LedgerUtxosByPaymentList | ComponentId::LedgerUtxosByPaymentDetails => {
+                self.utxos_by_payment_list
+                    .selected_item()
+                    .map(ToTableRow::identifier)
+            }
+            ComponentId::LedgerUtxosByAssetList | ComponentId::LedgerUtxosByAssetDetails => self
+                .utxos_by_asset_list
+                .selected_item()
+                .map(ToTableRow::identifier),
+
+            _ => None,
+        }
+    }
+
+    fn get_browse_details_id(&self) -> ComponentId {
+        match self.browse_options.model.selected_item() {
+            Some(LedgerBrowse::Accounts) => ComponentId::LedgerAccountDetails,
+            Some(LedgerBrowse::BlockIssuers) => ComponentId::LedgerBlockIssuerDetails,
+            Some(LedgerBrowse::BlockProduction) => ComponentId::LedgerBlockProductionDetails,
+            Some(LedgerBrowse::Committee) => ComponentId::LedgerCommitteeDetails,
+            Some(LedgerBrowse::DReps) => ComponentId::LedgerDRepDetails,
+            Some(LedgerBrowse::Pools) => ComponentId::LedgerPoolDetails,
+            Some(LedgerBrowse::Proposals) => ComponentId::LedgerProposalDetails,
+            Some(LedgerBrowse::Utxos) => ComponentId::LedgerUtxoDetails,
+            Some(LedgerBrowse::ProtocolParameters) => ComponentId::LedgerProtocolParamDetails,
+            Some(LedgerBrowse::EpochState) => ComponentId::LedgerEpochStateDetails,
+            Some(LedgerBrowse::Governance) => ComponentId::LedgerGovernanceDetails,
+            Some(LedgerBrowse::StakeDistribution) => ComponentId::LedgerStakeDetails,
+            None => ComponentId::LedgerAccountDetails,
+        }
+    }
+
     fn build_layout_spec(&self) -> LayoutSpec {
         let ledger_mode = self.mode_tabs.selected();
 
         let header_constraints = match ledger_mode {
-            LedgerMode::Browse => vec![(Constraint::Fill(1), Left(ComponentId::LedgerModeTabs))],
+            LedgerMode::Browse | LedgerMode::Diff => vec![
+                (Constraint::Fill(1), Left(ComponentId::LedgerModeTabs)),
+                (
+                    Constraint::Length(44),
+                    Left(ComponentId::LedgerRefreshStatus),
+                ),
+            ],
             LedgerMode::Search => vec![
                 (Constraint::Length(20), Left(ComponentId::LedgerModeTabs)),
                 (Constraint::Fill(1), Left(ComponentId::SearchBar)),
+                (
+                    Constraint::Length(44),
+                    Left(ComponentId::LedgerRefreshStatus),
+                ),
             ],
         };
         let header_spec = LayoutSpec {
@@ -212,15 +1079,41 @@ impl LedgerPageComponent {
             constraints: header_constraints,
         };
 
-        let (options_id, list_id) = match ledger_mode {
+        let (options_id, list_id, details_id) = match ledger_mode {
             LedgerMode::Browse => (
                 ComponentId::LedgerBrowseOptions,
                 self.get_active_list_component_id(),
+                self.get_browse_details_id(),
             ),
-            LedgerMode::Search => (
-                ComponentId::LedgerSearchOptions,
-                ComponentId::LedgerUtxosByAddrList,
-            ),
+            LedgerMode::Search => {
+                let (list_id, details_id) = self.get_active_search_component_ids();
+                (ComponentId::LedgerSearchOptions, list_id, details_id)
+            }
+            // No options to pick from, the details show both ledgers side by side
+            LedgerMode::Diff => {
+                return LayoutSpec {
+                    direction: Direction::Vertical,
+                    constraints: vec![
+                        (Constraint::Length(3), Right(header_spec)),
+                        (
+                            Constraint::Fill(1),
+                            Right(LayoutSpec {
+                                direction: Direction::Horizontal,
+                                constraints: vec![
+                                    (
+                                        Constraint::Percentage(30),
+                                        Left(ComponentId::LedgerDiffList),
+                                    ),
+                                    (
+                                        Constraint::Percentage(70),
+                                        Left(ComponentId::LedgerDiffDetails),
+                                    ),
+                                ],
+                            }),
+                        ),
+                    ],
+                };
+            }
         };
 
         let left_col_spec = LayoutSpec {
@@ -231,24 +1124,22 @@ impl LedgerPageComponent {
             ],
         };
 
-        let details_id = match ledger_mode {
-            LedgerMode::Browse => match self.browse_options.model.selected_item() {
-                Some(LedgerBrowse::Accounts) => ComponentId::LedgerAccountDetails,
-                Some(LedgerBrowse::BlockIssuers) => ComponentId::LedgerBlockIssuerDetails,
-                Some(LedgerBrowse::DReps) => ComponentId::LedgerDRepDetails,
-                Some(LedgerBrowse::Pools) => ComponentId::LedgerPoolDetails,
-                Some(LedgerBrowse::Proposals) => ComponentId::LedgerProposalDetails,
-                Some(LedgerBrowse::Utxos) => ComponentId::LedgerUtxoDetails,
-                None => ComponentId::LedgerAccountDetails,
-            },
-            LedgerMode::Search => ComponentId::LedgerUtxosByAddrDetails,
+        let right_col = match ledger_mode {
+            LedgerMode::Search => Right(LayoutSpec {
+                direction: Direction::Vertical,
+                constraints: vec![
+                    (Constraint::Fill(3), Left(details_id)),
+                    (Constraint::Fill(1), Left(ComponentId::LedgerUtxoTotals)),
+                ],
+            }),
+            _ => Left(details_id),
         };
 
         let body_spec = LayoutSpec {
             direction: Direction::Horizontal,
             constraints: vec![
                 (Constraint::Percentage(20), Right(left_col_spec)),
-                (Constraint::Percentage(80), Left(details_id)),
+                (Constraint::Percentage(80), right_col),
             ],
         };
 
@@ -269,7 +1160,84 @@ impl LedgerPageComponent {
     }
 
     fn handle_search(&mut self, query: &str) {
-        self.utxos_by_addr_list.handle_search(query);
+        match self.search_options.model.selected_item() {
+            Some(LedgerSearch::UtxosByAddress) | None => {
+                self.utxos_by_addr_list.handle_search(query)
+            }
+            Some(LedgerSearch::UtxoByInput) => self.utxo_by_input_list.handle_search(query),
+            Some(LedgerSearch::UtxosByStakeCredential) => {
+                self.utxos_by_stake_list.handle_search(query)
+            }
+            Some(LedgerSearch::UtxosByPaymentCredential) => {
+                self.utxos_by_payment_list.handle_search(query)
+            }
+            Some(LedgerSearch::UtxosByAsset) => self.utxos_by_asset_list.handle_search(query),
+        }
+    }
+
+    /// Exports the focused list or details.
+    pub fn handle_export(&mut self, format: ExportFormat) -> Vec<Action> {
+        let active_focus = *self.active_focus.read().unwrap();
+        let job = match active_focus {
+            // Lists
+            ComponentId::LedgerAccountsList => self.accounts_list.export(format),
+            ComponentId::LedgerBlockIssuersList => self.block_issuers_list.export(format),
+            ComponentId::LedgerCommitteeList => self.committee_list.export(format),
+            ComponentId::LedgerDRepsList => self.dreps_list.export(format),
+            ComponentId::LedgerPoolsList => self.pools_list.export(format),
+            ComponentId::LedgerProposalsList => self.proposals_list.export(format),
+            ComponentId::LedgerUtxosList => self.utxos_list.export(format),
+            ComponentId::LedgerUtxosByAddrList => self.utxos_by_addr_list.export(format),
+            ComponentId::LedgerUtxoByInputList => self.utxo_by_input_list.export(format),
+            ComponentId::LedgerUtxosByStakeList => self.utxos_by_stake_list.export(format),
+            ComponentId::LedgerUtxosByPaymentList => self.utxos_by_payment_list.export(format),
+            ComponentId::LedgerUtxosByAssetList => self.utxos_by_asset_list.export(format),
+
+            // Details
+            ComponentId::LedgerAccountDetails => self
+                .account_details
+                .export(self.selected_account.as_ref().map(|a| &a.account), format),
+            ComponentId::LedgerBlockIssuerDetails => self
+                .block_details
+                .export(self.block_issuers_list.model.selected_item(), format),
+            ComponentId::LedgerCommitteeDetails => self
+                .committee_details
+                .export(self.committee_list.model.selected_item(), format),
+            ComponentId::LedgerDRepDetails => self
+                .drep_details
+                .export(self.dreps_list.model.selected_item(), format),
+            ComponentId::LedgerPoolDetails => self
+                .pool_details
+                .export(self.pools_list.model.selected_item(), format),
+            ComponentId::LedgerProposalDetails => self
+                .proposal_details
+                .export(self.proposals_list.model.selected_item(), format),
+            ComponentId::LedgerUtxoDetails => self
+                .utxo_details
+                .export(self.utxos_list.model.selected_item(), format),
+            ComponentId::LedgerUtxosByAddrDetails => self
+                .utxo_by_addr_details
+                .export(self.utxos_by_addr_list.selected_item(), format),
+            ComponentId::LedgerUtxoByInputDetails => self
+                .utxo_by_input_details
+                .export(self.utxo_by_input_list.selected_item(), format),
+            ComponentId::LedgerUtxosByStakeDetails => self
+                .utxo_by_stake_details
+                .export(self.utxos_by_stake_list.selected_item(), format),
+            ComponentId::LedgerUtxosByPaymentDetails => self
+                .utxo_by_payment_details
+                .export(self.utxos_by_payment_list.selected_item(), format),
+            ComponentId::LedgerUtxosByAssetDetails => self
+                .utxo_by_asset_details
+                .export(self.utxos_by_asset_list.selected_item(), format),
+
+            other => {
+                warn!("Nothing to export in {}", other);
+                None
+            }
+        };
+        self.exports.push(job);
+        Vec::new()
     }
 
     pub fn handle_navigation(&mut self, direction: MoveFocus) -> Vec<Action> {
@@ -306,6 +1274,9 @@ impl LedgerPageComponent {
                 ComponentId::SearchBar => {
                     self.search_bar.render_focused(frame, area, is_focused);
                 }
+                ComponentId::LedgerRefreshStatus => {
+                    self.draw_refresh_status(frame, area);
+                }
 
                 // --- Options ---
                 ComponentId::LedgerBrowseOptions => {
@@ -323,6 +1294,13 @@ impl LedgerPageComponent {
                     self.block_issuers_list
                         .render_focused(frame, area, is_focused);
                 }
+                ComponentId::LedgerBlockProductionList => {
+                    self.block_production_list
+                        .render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerCommitteeList => {
+                    self.committee_list.render_focused(frame, area, is_focused);
+                }
                 ComponentId::LedgerDRepsList => {
                     self.dreps_list.render_focused(frame, area, is_focused);
                 }
@@ -335,14 +1313,47 @@ impl LedgerPageComponent {
                 ComponentId::LedgerUtxosList => {
                     self.utxos_list.render_focused(frame, area, is_focused);
                 }
+                ComponentId::LedgerProtocolParamsList => {
+                    self.protocol_params_list
+                        .render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerEpochStateList => {
+                    self.epoch_state_list
+                        .render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerGovernanceList => {
+                    self.governance_list.render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerStakeList => {
+                    self.stake_list.render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerDiffList => {
+                    self.diff_list.render_focused(frame, area, is_focused);
+                }
                 ComponentId::LedgerUtxosByAddrList => {
                     self.utxos_by_addr_list
                         .render_focused(frame, area, is_focused);
                 }
+                ComponentId::LedgerUtxoByInputList => {
+                    self.utxo_by_input_list
+                        .render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerUtxosByStakeList => {
+                    self.utxos_by_stake_list
+                        .render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerUtxosByPaymentList => {
+                    self.utxos_by_payment_list
+                        .render_focused(frame, area, is_focused);
+                }
+                ComponentId::LedgerUtxosByAssetList => {
+                    self.utxos_by_asset_list
+                        .render_focused(frame, area, is_focused);
+                }
 
                 // --- Details ---
                 ComponentId::LedgerAccountDetails => {
-                    let item = self.accounts_list.model.selected_item();
+                    let item = self.selected_account.as_ref();
                     self.account_details
                         .render_with_data(frame, area, is_focused, item);
                 }
@@ -351,6 +1362,16 @@ impl LedgerPageComponent {
                     self.block_details
                         .render_with_data(frame, area, is_focused, item);
                 }
+                ComponentId::LedgerBlockProductionDetails => {
+                    let item = self.block_production_list.model.selected_item();
+                    self.block_production_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerCommitteeDetails => {
+                    let item = self.committee_list.model.selected_item();
+                    self.committee_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
                 ComponentId::LedgerDRepDetails => {
                     let item = self.dreps_list.model.selected_item();
                     self.drep_details
@@ -371,11 +1392,83 @@ impl LedgerPageComponent {
                     self.utxo_details
                         .render_with_data(frame, area, is_focused, item);
                 }
+                ComponentId::LedgerProtocolParamDetails => {
+                    let item = self.protocol_params_list.model.selected_item();
+                    self.protocol_param_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerEpochStateDetails => {
+                    let item = self.epoch_state_list.model.selected_item();
+                    self.epoch_state_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerGovernanceDetails => {
+                    let item = self.governance_list.model.selected_item();
+                    self.governance_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerStakeDetails => {
+                    let [details_area, chart_area] =
+                        Layout::vertical([Constraint::Length(7), Constraint::Fill(1)]).areas(area);
+                    let model = &self.stake_list.model;
+                    self.stake_details.render_with_data(
+                        frame,
+                        details_area,
+                        is_focused,
+                        model.selected_item(),
+                    );
+                    draw_stake_chart(
+                        frame,
+                        chart_area,
+                        &model.pools,
+                        model.selected_index(),
+                        model.saturation_point,
+                        is_focused,
+                    );
+                }
+                ComponentId::LedgerDiffDetails => {
+                    let item = self.diff_list.model.selected_item();
+                    self.diff_details.render_side_by_side(
+                        frame,
+                        area,
+                        is_focused,
+                        item.and_then(|d| d.left.as_ref()),
+                        item.and_then(|d| d.right.as_ref()),
+                    );
+                }
                 ComponentId::LedgerUtxosByAddrDetails => {
                     let item = self.utxos_by_addr_list.selected_item();
                     self.utxo_by_addr_details
                         .render_with_data(frame, area, is_focused, item);
                 }
+                ComponentId::LedgerUtxoByInputDetails => {
+                    let item = self.utxo_by_input_list.selected_item();
+                    self.utxo_by_input_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerUtxosByStakeDetails => {
+                    let item = self.utxos_by_stake_list.selected_item();
+                    self.utxo_by_stake_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerUtxosByPaymentDetails => {
+                    let item = self.utxos_by_payment_list.selected_item();
+                    self.utxo_by_payment_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerUtxosByAssetDetails => {
+                    let item = self.utxos_by_asset_list.selected_item();
+                    self.utxo_by_asset_details
+                        .render_with_data(frame, area, is_focused, item);
+                }
+                ComponentId::LedgerUtxoTotals => {
+                    self.utxo_totals_details.render_with_data(
+                        frame,
+                        area,
+                        is_focused,
+                        Some(&self.utxo_totals),
+                    );
+                }
                 _ => {}
             }
         }
@@ -397,6 +1490,85 @@ impl Component for LedgerPageComponent {
         let layout = self.last_layout.read().unwrap().clone();
         let mut active_focus = *self.active_focus.read().unwrap();
 
+        // Copy the identifier of the selected item
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Char('y')
+            && !self.is_prompting(active_focus)
+            && let Some(text) = self.yank_text(active_focus)
+        {
+            return vec![Action::CopyToClipboard(text)];
+        }
+
+        // Re-open the db to catch up with the node
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Char('R')
+            && active_focus != ComponentId::SearchBar
+            && !self.is_prompting(active_focus)
+        {
+            self.refresh.start(self.db.clone());
+            return vec![Action::Render];
+        }
+
+        // Show only the protocol parameters changed since genesis
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Char('c')
+            && active_focus == ComponentId::LedgerProtocolParamsList
+        {
+            self.toggle_changed_params();
+            return vec![Action::Render];
+        }
+
+        // Drill from a pool's block production into the slots it issued
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Enter
+            && active_focus == ComponentId::LedgerBlockProductionList
+            && let Some(list_id) = self.drill_into_block_production()
+        {
+            *self.active_focus.write().unwrap() = list_id;
+            return vec![Action::SetFocus(list_id)];
+        }
+
+        // Jump from a proposal to the committee members that voted on it
+        if let Event::Key(key) = event
+            && key.code == KeyCode::Char('m')
+            && matches!(
+                active_focus,
+                ComponentId::LedgerGovernanceList | ComponentId::LedgerGovernanceDetails
+            )
+        {
+            return match self.jump_to_committee_member() {
+                Some(list_id) => {
+                    *self.active_focus.write().unwrap() = list_id;
+                    vec![Action::SetFocus(list_id)]
+                }
+                None => Vec::new(),
+            };
+        }
+
+        // Jump from an account to the pool or DRep it delegates to
+        if let Event::Key(key) = event
+            && matches!(
+                active_focus,
+                ComponentId::LedgerAccountsList | ComponentId::LedgerAccountDetails
+            )
+            && !self.is_prompting(active_focus)
+        {
+            let target = match key.code {
+                KeyCode::Char('p') => Some(LedgerBrowse::Pools),
+                KeyCode::Char('d') => Some(LedgerBrowse::DReps),
+                _ => None,
+            };
+            if let Some(target) = target {
+                return match self.jump_from_account(target) {
+                    Some(list_id) => {
+                        *self.active_focus.write().unwrap() = list_id;
+                        vec![Action::SetFocus(list_id)]
+                    }
+                    None => Vec::new(),
+                };
+            }
+        }
+
         let mut actions = crate::components::handle_container_event(
             &layout,
             &mut active_focus,
@@ -421,7 +1593,53 @@ impl Component for LedgerPageComponent {
     }
 
     fn tick(&mut self) -> Vec<Action> {
+        // Only the lists shown so far have changes worth highlighting
+        if self.mode_tabs.selected() == LedgerMode::Browse
+            && let Some(column) = Self::list_column(self.get_active_list_component_id())
+        {
+            self.refresh.watch(column);
+        }
+        if let Some(refreshed) = self.refresh.poll(&self.db) {
+            self.apply_refresh(refreshed);
+        }
+        self.accounts_list.tick();
+        self.block_issuers_list.tick();
+        self.block_production_list.tick();
+        self.committee_list.tick();
+        self.dreps_list.tick();
+        self.pools_list.tick();
+        self.proposals_list.tick();
+        self.utxos_list.tick();
+        // The stake distribution scans the whole UTXO set, so only start it
+        // once a list needing it is shown
+        if self.mode_tabs.selected() == LedgerMode::Browse
+            && matches!(
+                self.get_active_list_component_id(),
+                ComponentId::LedgerStakeList | ComponentId::LedgerGovernanceList
+            )
+        {
+            self.stake.start();
+        }
+        if let Some(stake) = self.stake.poll() {
+            self.governance_list.model = Self::governance_model(self.db.clone(), stake.clone());
+            self.stake_list.model.set_distribution(&stake);
+        }
+        self.stake_list.model.set_progress(&self.stake);
+        self.governance_list.tick();
+        self.stake_list.tick();
+        // Both ledgers are walked in full, so only diff them once shown
+        if self.mode_tabs.selected() == LedgerMode::Diff && self.diff_list.model.is_waiting() {
+            self.diff_list.model = Self::diff_model(self.db.clone(), self.compare_db.clone());
+        }
+        self.diff_list.tick();
         self.utxos_by_addr_list.tick();
+        self.utxo_by_input_list.tick();
+        self.utxos_by_stake_list.tick();
+        self.utxos_by_payment_list.tick();
+        self.utxos_by_asset_list.tick();
+        self.update_utxo_totals();
+        self.update_selected_account();
+        self.exports.poll();
 
         let layout = self.last_layout.read().unwrap();
 
@@ -439,6 +1657,14 @@ impl Component for LedgerPageComponent {
                 .model
                 .set_height(area.height as usize);
         }
+        if let Some(area) = layout.get(&ComponentId::LedgerBlockProductionList) {
+            self.block_production_list
+                .model
+                .set_height(area.height as usize);
+        }
+        if let Some(area) = layout.get(&ComponentId::LedgerCommitteeList) {
+            self.committee_list.model.set_height(area.height as usize);
+        }
         if let Some(area) = layout.get(&ComponentId::LedgerDRepsList) {
             self.dreps_list.model.set_height(area.height as usize);
         }
@@ -451,6 +1677,23 @@ impl Component for LedgerPageComponent {
         if let Some(area) = layout.get(&ComponentId::LedgerUtxosList) {
             self.utxos_list.model.set_height(area.height as usize);
         }
+        if let Some(area) = layout.get(&ComponentId::LedgerProtocolParamsList) {
+            self.protocol_params_list
+                .model
+                .set_height(area.height as usize);
+        }
+        if let Some(area) = layout.get(&ComponentId::LedgerEpochStateList) {
+            self.epoch_state_list.model.set_height(area.height as usize);
+        }
+        if let Some(area) = layout.get(&ComponentId::LedgerGovernanceList) {
+            self.governance_list.model.set_height(area.height as usize);
+        }
+        if let Some(area) = layout.get(&ComponentId::LedgerStakeList) {
+            self.stake_list.model.set_height(area.height as usize);
+        }
+        if let Some(area) = layout.get(&ComponentId::LedgerDiffList) {
+            self.diff_list.model.set_height(area.height as usize);
+        }
 
         Vec::new()
     }
@@ -1,10 +1,11 @@
 use crate::{
     components::Component,
+    export::ExportJob,
     model::list_view::ListModelView,
-    states::{Action, ComponentId},
+    states::{Action, ComponentId, ExportFormat},
     ui::to_list_item::ToListItem,
 };
-use crossterm::event::Event;
+use crossterm::event::{Event, KeyEvent};
 use ratatui::{
     Frame,
     crossterm::event::{KeyCode, MouseButton, MouseEventKind},
@@ -23,6 +24,21 @@ pub trait ListModel: Send + Sync + 'static {
     fn retreat_window(&mut self);
     fn advance_window(&mut self);
     fn set_height(&mut self, height: usize);
+
+    /// Called on every tick, for models loading data in the background.
+    fn poll(&mut self) {}
+
+    /// Gives the model a chance to consume a key before list navigation.
+    /// Returns the resulting actions when the key was consumed.
+    fn handle_key(&mut self, _key: &KeyEvent) -> Option<Vec<Action>> {
+        None
+    }
+
+    /// Exports the items of the list to a file named after `name`. Lists
+    /// with nothing worth exporting ignore it.
+    fn export(&self, _name: String, _format: ExportFormat) -> Option<ExportJob> {
+        None
+    }
 }
 
 // Implement for the Static List Model (based on StreamingIter)
@@ -91,6 +107,10 @@ where
     pub fn render_focused(&self, f: &mut Frame, area: Rect, is_focused: bool) {
         self.model.draw(f, area, is_focused);
     }
+
+    pub fn export(&self, format: ExportFormat) -> Option<ExportJob> {
+        self.model.export(self.id.to_string(), format)
+    }
 }
 
 impl<M> Component for ListComponent<M>
@@ -109,7 +129,18 @@ where
         self
     }
 
+    fn tick(&mut self) -> Vec<Action> {
+        self.model.poll();
+        Vec::new()
+    }
+
     fn handle_event(&mut self, event: &Event, area: Rect) -> Vec<Action> {
+        if let Event::Key(key) = event
+            && let Some(actions) = self.model.handle_key(key)
+        {
+            return actions;
+        }
+
         match event {
             Event::Key(key) => match key.code {
                 KeyCode::Up => {
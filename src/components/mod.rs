@@ -8,12 +8,15 @@ pub mod chain_page;
 pub mod chain_search;
 pub mod details;
 pub mod flame_graph;
+pub mod fork_tree;
+pub mod ledger_list;
 pub mod ledger_page;
 pub mod list;
 pub mod otel_page;
 pub mod root;
 pub mod search_bar;
 pub mod search_list;
+pub mod stake_ranking;
 pub mod tabs;
 pub mod trace_list;
 
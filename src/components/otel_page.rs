@@ -5,8 +5,12 @@ use crate::{
     },
     controller::{LayoutSpec, MoveFocus, find_next_focus, walk_layout},
     model::otel_view::OtelViewState,
-    otel::{TraceGraphSnapshot, graph::TraceGraph, id::SpanId, span_ext::SpanExt},
+    otel::{
+        TraceGraphSnapshot, graph::TraceGraph, id::SpanId, service::CollectorStatusHandle,
+        span_ext::SpanExt,
+    },
     states::{Action, ComponentId},
+    view::collector_status::{draw_collector_status, draw_store_stats},
 };
 use crossterm::event::{Event, KeyCode, MouseEventKind};
 use either::Either::{Left, Right};
@@ -24,19 +28,21 @@ pub struct OtelPageComponent {
     pub trace_list: TraceListComponent,
     pub flame_graph: FlameGraphComponent,
     pub span_details: DetailsComponent<Span>,
+    collector_status: CollectorStatusHandle,
 
     last_layout: RwLock<ComponentLayout>,
     active_focus: RwLock<ComponentId>,
 }
 
 impl OtelPageComponent {
-    pub fn new(trace_graph: TraceGraphSnapshot) -> Self {
+    pub fn new(trace_graph: TraceGraphSnapshot, collector_status: CollectorStatusHandle) -> Self {
         Self {
             id: ComponentId::OtelPage,
             view_state: OtelViewState::new(trace_graph),
             trace_list: TraceListComponent::new(ComponentId::OtelTraceList),
             flame_graph: FlameGraphComponent::new(ComponentId::OtelFlameGraph),
             span_details: DetailsComponent::new(ComponentId::OtelSpanDetails, "Span Details"),
+            collector_status,
 
             last_layout: RwLock::new(HashMap::new()),
             active_focus: RwLock::new(ComponentId::OtelTraceList),
@@ -98,31 +104,43 @@ impl OtelPageComponent {
     pub fn calculate_layout(&self, area: Rect) -> ComponentLayout {
         let spec = LayoutSpec {
             direction: Direction::Vertical,
-            constraints: vec![(
-                Constraint::Fill(1),
-                Right(LayoutSpec {
-                    direction: Direction::Horizontal,
-                    constraints: vec![
-                        (Constraint::Percentage(10), Left(ComponentId::OtelTraceList)),
-                        (
-                            Constraint::Percentage(90),
-                            Right(LayoutSpec {
-                                direction: Direction::Horizontal,
-                                constraints: vec![
-                                    (
-                                        Constraint::Percentage(70),
-                                        Left(ComponentId::OtelFlameGraph),
-                                    ),
-                                    (
-                                        Constraint::Percentage(30),
-                                        Left(ComponentId::OtelSpanDetails),
-                                    ),
-                                ],
-                            }),
-                        ),
-                    ],
-                }),
-            )],
+            constraints: vec![
+                (
+                    Constraint::Length(3),
+                    Right(LayoutSpec {
+                        direction: Direction::Horizontal,
+                        constraints: vec![
+                            (Constraint::Fill(1), Left(ComponentId::OtelCollectorStatus)),
+                            (Constraint::Length(56), Left(ComponentId::OtelStoreStats)),
+                        ],
+                    }),
+                ),
+                (
+                    Constraint::Fill(1),
+                    Right(LayoutSpec {
+                        direction: Direction::Horizontal,
+                        constraints: vec![
+                            (Constraint::Percentage(10), Left(ComponentId::OtelTraceList)),
+                            (
+                                Constraint::Percentage(90),
+                                Right(LayoutSpec {
+                                    direction: Direction::Horizontal,
+                                    constraints: vec![
+                                        (
+                                            Constraint::Percentage(70),
+                                            Left(ComponentId::OtelFlameGraph),
+                                        ),
+                                        (
+                                            Constraint::Percentage(30),
+                                            Left(ComponentId::OtelSpanDetails),
+                                        ),
+                                    ],
+                                }),
+                            ),
+                        ],
+                    }),
+                ),
+            ],
         };
 
         let mut layout = HashMap::new();
@@ -139,6 +157,14 @@ impl OtelPageComponent {
         }
 
         let current_focus = *self.active_focus.read().unwrap();
+        if let Some(rect) = my_layout.get(&ComponentId::OtelCollectorStatus) {
+            draw_collector_status(frame, *rect, &self.collector_status.read().unwrap());
+        }
+
+        if let Some(rect) = my_layout.get(&ComponentId::OtelStoreStats) {
+            draw_store_stats(frame, *rect, &self.view_state.trace_graph.load());
+        }
+
         if let Some(rect) = my_layout.get(&ComponentId::OtelTraceList) {
             let is_focused = current_focus == ComponentId::OtelTraceList;
             self.trace_list.render_focused(frame, *rect, is_focused);
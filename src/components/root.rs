@@ -4,10 +4,12 @@ use crate::{
         ledger_page::LedgerPageComponent, otel_page::OtelPageComponent, tabs::TabsComponent,
     },
     controller::{LayoutSpec, MoveFocus, walk_layout},
-    metrics::page::MetricsPageComponent,
-    otel::TraceGraphSnapshot,
+    metrics::{model::MetricUpdate, page::MetricsPageComponent},
+    model::ledger_refresh::LedgerRefresh,
+    otel::{TraceGraphSnapshot, service::CollectorStatusHandle},
     states::{Action, ComponentId, InspectOption},
 };
+use amaru_kernel::network::NetworkName;
 use amaru_stores::rocksdb::{ReadOnlyRocksDB, consensus::ReadOnlyChainDB};
 use crossterm::event::{Event, KeyCode, KeyModifiers};
 use either::Either::Left;
@@ -16,6 +18,8 @@ use ratatui::{
     layout::{Constraint, Direction, Rect},
 };
 use std::{any::Any, collections::HashMap, sync::Arc};
+use tokio::sync::mpsc::Receiver;
+use tracing::warn;
 
 pub struct RootComponent {
     id: ComponentId,
@@ -29,16 +33,26 @@ pub struct RootComponent {
 impl RootComponent {
     pub fn new(
         ledger_db: Arc<ReadOnlyRocksDB>,
+        ledger_refresh: LedgerRefresh,
         chain_db: Arc<ReadOnlyChainDB>,
+        compare_ledger_db: Option<Arc<ReadOnlyRocksDB>>,
         trace_graph: TraceGraphSnapshot,
+        metric_updates: Receiver<MetricUpdate>,
+        collector_status: CollectorStatusHandle,
+        network: NetworkName,
     ) -> Self {
         Self {
             id: ComponentId::Root,
             tabs: TabsComponent::new(ComponentId::InspectTabs, false),
-            ledger_page: LedgerPageComponent::new(ledger_db),
-            chain_page: ChainPageComponent::new(chain_db),
-            otel_page: OtelPageComponent::new(trace_graph),
-            metrics_page: MetricsPageComponent::new_with_service(),
+            ledger_page: LedgerPageComponent::new(
+                ledger_db.clone(),
+                compare_ledger_db,
+                ledger_refresh,
+                network,
+            ),
+            chain_page: ChainPageComponent::new(chain_db, ledger_db),
+            otel_page: OtelPageComponent::new(trace_graph, collector_status.clone()),
+            metrics_page: MetricsPageComponent::new(metric_updates, collector_status),
         }
     }
 
@@ -165,6 +179,16 @@ impl Component for RootComponent {
     }
 
     fn handle_action(&mut self, action: Action) -> Vec<Action> {
+        if let Action::Export(format) = action {
+            return match self.tabs.selected() {
+                InspectOption::Ledger => self.ledger_page.handle_export(format),
+                _ => {
+                    warn!("Export is only available on the ledger page");
+                    Vec::new()
+                }
+            };
+        }
+
         // Map Action::Focus* to MoveFocus enum
         let direction = match action {
             Action::FocusUp => MoveFocus::Up,
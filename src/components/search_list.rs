@@ -1,13 +1,15 @@
 use crate::{
     components::{Component, async_list::AsyncListModel, list::ListModel},
+    export::{ExportJob, Exportable, spawn_export},
     model::search::SearchCache,
-    states::{Action, ComponentId},
+    states::{Action, ComponentId, ExportFormat},
     ui::to_list_item::ToListItem,
     view::empty_list::draw_empty_list,
 };
 use crossterm::event::{Event, KeyCode, MouseButton, MouseEventKind};
 use ratatui::prelude::*;
 use std::{any::Any, hash::Hash, str::FromStr};
+use tracing::warn;
 
 pub trait SearchProvider<Q, R>: Send + Sync
 where
@@ -26,6 +28,8 @@ where
     title: &'static str,
     provider: Box<dyn SearchProvider<Q, R>>,
     last_drag_y: Option<u16>,
+    /// Incremented every time the active result set changes.
+    generation: usize,
 }
 
 impl<Q, R> SearchListComponent<Q, R>
@@ -44,6 +48,7 @@ where
             title,
             provider,
             last_drag_y: None,
+            generation: 0,
         }
     }
 
@@ -54,14 +59,39 @@ where
 
         if self.state.results.contains_key(&query) {
             self.state.parsed = Some(query);
+            self.generation += 1;
             return;
         }
 
         if let Some(model) = self.provider.search(&query) {
             self.state.cache_result(query, model);
+            self.generation += 1;
         }
     }
 
+    /// Replaces the provider, dropping the cached results. The active search
+    /// runs again against the new provider.
+    pub fn set_provider(&mut self, provider: Box<dyn SearchProvider<Q, R>>) {
+        self.provider = provider;
+        let active = self.state.parsed.take();
+        self.state = SearchCache::default();
+        if let Some(query) = active
+            && let Some(model) = self.provider.search(&query)
+        {
+            self.state.cache_result(query, model);
+        }
+        self.generation += 1;
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The model holding the results of the active search, if any.
+    pub fn current_results(&self) -> Option<&AsyncListModel<R>> {
+        self.state.get_current_res()
+    }
+
     pub fn selected_item(&self) -> Option<&R> {
         self.state.get_current_res().and_then(|m| m.selected_item())
     }
@@ -79,6 +109,21 @@ where
     }
 }
 
+impl<Q, R> SearchListComponent<Q, R>
+where
+    Q: Clone + Eq + Hash + FromStr + Send + Sync + 'static,
+    R: ToListItem + Exportable + Clone + Send + Sync + 'static,
+{
+    /// Exports the results of the active search loaded so far.
+    pub fn export(&self, format: ExportFormat) -> Option<ExportJob> {
+        let Some(items) = self.current_results().map(|model| model.buffer.clone()) else {
+            warn!("Nothing to export in {}, no search was run", self.id);
+            return None;
+        };
+        Some(spawn_export(self.id.to_string(), format, move || items))
+    }
+}
+
 impl<Q, R> Component for SearchListComponent<Q, R>
 where
     Q: Clone + Eq + Hash + FromStr + Send + Sync + 'static,
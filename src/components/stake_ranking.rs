@@ -0,0 +1,163 @@
+use crate::{
+    components::list::ListModel,
+    model::{
+        ledger_state::load_epoch_state,
+        stake::{PoolStake, StakeDistribution, StakeDistributionJob, saturation_point},
+    },
+    view::list::ListViewState,
+};
+use amaru_ledger::store::ReadStore;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use std::sync::Arc;
+use tracing::warn;
+
+/// How far the shared stake distribution job went.
+#[derive(Clone, Copy, Debug, Default)]
+enum JobStatus {
+    #[default]
+    NotStarted,
+    Scanning(usize),
+    Failed,
+}
+
+/// The pools ranked by delegated stake, from the stake distribution shared
+/// with the governance tallies.
+pub struct StakeRankingModel {
+    db: Arc<ReadOnlyRocksDB>,
+    status: JobStatus,
+    pub pools: Vec<PoolStake>,
+    pub saturation_point: Option<u64>,
+    view: ListViewState,
+}
+
+impl StakeRankingModel {
+    pub fn new(title: &'static str, db: Arc<ReadOnlyRocksDB>) -> Self {
+        Self {
+            db,
+            status: JobStatus::default(),
+            pools: Vec::new(),
+            saturation_point: None,
+            view: ListViewState::new(title),
+        }
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.saturation_point.is_none()
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        (!self.pools.is_empty()).then(|| self.view.selected_index())
+    }
+
+    /// Follows the progress of the job aggregating the distribution.
+    pub fn set_progress(&mut self, job: &StakeDistributionJob) {
+        self.status = if job.failed {
+            JobStatus::Failed
+        } else if job.is_started() {
+            JobStatus::Scanning(job.scanned)
+        } else {
+            JobStatus::NotStarted
+        };
+    }
+
+    /// Ranks the pools of the distribution once it is loaded.
+    pub fn set_distribution(&mut self, distribution: &StakeDistribution) {
+        let params = (
+            ReadStore::protocol_parameters(&*self.db),
+            load_epoch_state(&self.db),
+        );
+        let saturation_point = match params {
+            (Ok(params), Ok(state)) => {
+                saturation_point(state.reserves, u64::from(params.optimal_stake_pools_count))
+            }
+            (Err(e), _) => {
+                warn!("Failed to read the protocol parameters: {}", e);
+                self.status = JobStatus::Failed;
+                return;
+            }
+            (_, Err(e)) => {
+                warn!("Failed to read the reserves: {}", e);
+                self.status = JobStatus::Failed;
+                return;
+            }
+        };
+        self.pools = distribution.rank_pools(saturation_point);
+        self.saturation_point = Some(saturation_point);
+    }
+
+    fn draw_progress(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        let mut block = Block::default()
+            .title(self.view.title())
+            .borders(Borders::ALL);
+        if is_focused {
+            block = block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+
+        let status = match self.status {
+            JobStatus::NotStarted => "Not started".to_string(),
+            JobStatus::Failed => {
+                "Failed to compute the stake distribution, see the logs".to_string()
+            }
+            JobStatus::Scanning(0) => "Scanning UTXOs...".to_string(),
+            JobStatus::Scanning(scanned) => format!("Scanning UTXOs... {} scanned", scanned),
+        };
+        let text = vec![
+            Line::from(""),
+            Line::from(Span::styled(status, Style::default().fg(Color::Gray))),
+        ];
+        let widget = Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .block(block);
+        f.render_widget(widget, area);
+    }
+}
+
+impl ListModel for StakeRankingModel {
+    type Item = PoolStake;
+
+    fn draw(&self, f: &mut Frame, area: Rect, is_focused: bool) {
+        if self.is_loading() {
+            self.draw_progress(f, area, is_focused);
+        } else {
+            self.view.draw(f, area, &self.pools, is_focused);
+        }
+    }
+
+    fn selected_item(&self) -> Option<&PoolStake> {
+        self.pools.get(self.view.selected_index())
+    }
+
+    fn select_index_by_row(&mut self, relative_row: usize) {
+        self.view
+            .select_index_by_row(relative_row, self.pools.len());
+    }
+
+    fn cursor_back(&mut self) {
+        self.view.cursor_back();
+    }
+
+    fn cursor_next(&mut self) {
+        self.view.cursor_next(Some(self.pools.len()));
+    }
+
+    fn retreat_window(&mut self) {
+        self.view.retreat_window();
+    }
+
+    fn advance_window(&mut self) {
+        self.view.advance_window(Some(self.pools.len()));
+    }
+
+    fn set_height(&mut self, height: usize) {
+        self.view.set_height(height);
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)] // Remove this once you start using the code
 
-use std::{collections::HashMap, env, path::PathBuf};
+use std::{collections::HashMap, env, net::SocketAddr, path::PathBuf};
 
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -11,7 +11,7 @@ use ratatui::style::{Color, Modifier, Style};
 use serde::{Deserialize, de::Deserializer};
 use tracing::error;
 
-use crate::{app::Mode, states::Action};
+use crate::{app::Mode, cli::Cli, states::Action};
 
 const CONFIG: &str = include_str!("../.config/config.json5");
 
@@ -31,6 +31,71 @@ pub struct Config {
     pub keybindings: KeyBindings,
     #[serde(default)]
     pub styles: Styles,
+    #[serde(default)]
+    pub otel: OtelConfig,
+}
+
+/// The OTLP collector settings, from the `otel` section of the config file.
+/// Command line options take precedence.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OtelConfig {
+    /// Listen for OTLP/gRPC exports.
+    pub grpc: bool,
+    pub grpc_addr: SocketAddr,
+    /// Listen for OTLP/HTTP exports.
+    pub http: bool,
+    pub http_addr: SocketAddr,
+    /// How long received traces are kept, in seconds.
+    pub retention_secs: u64,
+    /// The most spans kept, the oldest traces are evicted beyond it.
+    pub max_spans: usize,
+    /// The approximate memory the kept spans may take, in MiB.
+    pub max_memory_mib: usize,
+    /// The span batches waiting to be processed before exporters wait.
+    pub queue_capacity: usize,
+}
+
+impl Default for OtelConfig {
+    fn default() -> Self {
+        Self {
+            grpc: true,
+            grpc_addr: SocketAddr::from(([0, 0, 0, 0], 4317)),
+            http: true,
+            http_addr: SocketAddr::from(([0, 0, 0, 0], 4318)),
+            retention_secs: 10 * 60,
+            max_spans: 1_000_000,
+            max_memory_mib: 512,
+            queue_capacity: 10_000,
+        }
+    }
+}
+
+impl OtelConfig {
+    /// Applies the collector options given on the command line.
+    pub fn override_with(mut self, args: &Cli) -> Self {
+        if let Some(addr) = args.otel_grpc_addr {
+            self.grpc_addr = addr;
+        }
+        if let Some(addr) = args.otel_http_addr {
+            self.http_addr = addr;
+        }
+        self.grpc &= !args.no_otel_grpc;
+        self.http &= !args.no_otel_http;
+        if let Some(secs) = args.trace_retention {
+            self.retention_secs = secs;
+        }
+        if let Some(max_spans) = args.max_spans {
+            self.max_spans = max_spans;
+        }
+        if let Some(mib) = args.max_trace_memory {
+            self.max_memory_mib = mib;
+        }
+        if let Some(capacity) = args.trace_queue {
+            self.queue_capacity = capacity;
+        }
+        self
+    }
 }
 
 lazy_static! {
@@ -446,6 +511,7 @@ fn parse_color(s: &str) -> Option<Color> {
 
 #[cfg(test)]
 mod tests {
+    use clap::Parser;
     use pretty_assertions::assert_eq;
 
     use super::*;
@@ -525,6 +591,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_otel_config_override() {
+        let args = Cli::parse_from([
+            "amaru-doctor",
+            "--otel-http-addr",
+            "127.0.0.1:14318",
+            "--no-otel-grpc",
+            "--max-spans",
+            "500",
+        ]);
+        let config = OtelConfig::default().override_with(&args);
+        assert!(!config.grpc);
+        assert!(config.http);
+        assert_eq!(config.http_addr, SocketAddr::from(([127, 0, 0, 1], 14318)));
+        assert_eq!(config.max_spans, 500);
+        assert_eq!(config.retention_secs, OtelConfig::default().retention_secs);
+    }
+
     #[test]
     fn test_simple_keys() {
         assert_eq!(
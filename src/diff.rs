@@ -0,0 +1,77 @@
+use crate::{
+    model::ledger_diff::{DiffKind, LedgerColumn, LedgerDifference, diff_ledgers},
+    ui::to_json::ToJson,
+};
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use anyhow::Result;
+use serde_json::json;
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+use strum::IntoEnumIterator;
+
+/// Differences of a column, by kind.
+#[derive(Default)]
+struct ColumnSummary {
+    removed: usize,
+    added: usize,
+    changed: usize,
+}
+
+/// Runs a headless diff of two ledgers, printing how many entries of each
+/// column were removed, added and changed. With `list`, every difference is
+/// also printed as a JSON line.
+pub fn run(left: &ReadOnlyRocksDB, right: &ReadOnlyRocksDB, list: bool) -> Result<()> {
+    let mut out = io::stdout().lock();
+    let mut summary: BTreeMap<String, ColumnSummary> = BTreeMap::new();
+    let mut write_error = None;
+    let columns: Vec<_> = LedgerColumn::iter().collect();
+    diff_ledgers(left, right, &columns, |difference| {
+        let counts = summary.entry(difference.column.to_string()).or_default();
+        match difference.kind() {
+            DiffKind::Removed => counts.removed += 1,
+            DiffKind::Added => counts.added += 1,
+            DiffKind::Changed => counts.changed += 1,
+        }
+        if list && let Err(e) = writeln!(out, "{}", difference_json(&difference)) {
+            write_error = Some(e);
+            return false;
+        }
+        true
+    })?;
+    if let Some(e) = write_error {
+        return Err(e.into());
+    }
+
+    writeln!(
+        out,
+        "{:<13} {:>10} {:>10} {:>10}",
+        "column", "removed", "added", "changed"
+    )?;
+    for column in LedgerColumn::iter() {
+        let column = column.to_string();
+        let counts = summary.remove(&column).unwrap_or_default();
+        writeln!(
+            out,
+            "{:<13} {:>10} {:>10} {:>10}",
+            column, counts.removed, counts.added, counts.changed
+        )?;
+    }
+    Ok(())
+}
+
+fn difference_json(difference: &LedgerDifference) -> serde_json::Value {
+    let kind = match difference.kind() {
+        DiffKind::Removed => "removed",
+        DiffKind::Added => "added",
+        DiffKind::Changed => "changed",
+    };
+    json!({
+        "column": difference.column,
+        "kind": kind,
+        "key": difference.identifier(),
+        "ledger": difference.left.as_ref().map(ToJson::to_json),
+        "compared": difference.right.as_ref().map(ToJson::to_json),
+    })
+}
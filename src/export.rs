@@ -0,0 +1,172 @@
+use crate::{
+    config::get_data_dir,
+    model::async_provider::AsyncProvider,
+    states::ExportFormat,
+    ui::{to_json::ToJson, to_table_row::ToTableRow},
+};
+use amaru_kernel::to_cbor;
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use std::{
+    borrow::{Borrow, Cow},
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+use tokio::sync::mpsc::error::TryRecvError;
+use tracing::{error, info};
+
+/// Items that can be written in every export format.
+pub trait Exportable: ToJson + ToTableRow + minicbor::Encode<()> {}
+
+impl<T> Exportable for T where T: ToJson + ToTableRow + minicbor::Encode<()> {}
+
+impl ExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "jsonl",
+            Self::Csv => "csv",
+            Self::Cbor => "cbor",
+        }
+    }
+}
+
+/// A fresh file under `<data dir>/exports`, named after what is exported.
+fn export_path(name: &str, format: ExportFormat) -> Result<PathBuf> {
+    let dir = get_data_dir().join("exports");
+    fs::create_dir_all(&dir)?;
+    let timestamp = Utc::now().format("%Y%m%dT%H%M%S%.3f");
+    Ok(dir.join(format!("{}-{}.{}", name, timestamp, format.extension())))
+}
+
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+fn write_csv_row<W: Write>(out: &mut W, fields: impl IntoIterator<Item = String>) -> Result<()> {
+    let fields: Vec<String> = fields
+        .into_iter()
+        .map(|field| csv_field(&field).into_owned())
+        .collect();
+    writeln!(out, "{}", fields.join(","))?;
+    Ok(())
+}
+
+/// Writes the items to `path`: JSON lines, CSV with the table columns as
+/// header, or a sequence of CBOR items. Returns the number of items written.
+pub fn write_items<T, I>(path: &Path, format: ExportFormat, items: I) -> Result<usize>
+where
+    T: Exportable,
+    I: IntoIterator,
+    I::Item: Borrow<T>,
+{
+    let mut out = BufWriter::new(File::create(path)?);
+    if format == ExportFormat::Csv {
+        write_csv_row(
+            &mut out,
+            T::COLUMNS.iter().map(|(header, _)| header.to_string()),
+        )?;
+    }
+
+    let mut count = 0;
+    for item in items {
+        let item = item.borrow();
+        match format {
+            ExportFormat::Json => writeln!(out, "{}", item.to_json())?,
+            ExportFormat::Csv => write_csv_row(&mut out, item.to_table_row())?,
+            ExportFormat::Cbor => out.write_all(&to_cbor(item))?,
+        }
+        count += 1;
+    }
+    out.flush()?;
+    Ok(count)
+}
+
+/// An export running in the background, which reports how many items were
+/// written where once done.
+pub struct ExportJob {
+    name: String,
+    provider: AsyncProvider<Result<(PathBuf, usize)>>,
+}
+
+/// Exports the items produced by `items` in the background, so that
+/// streaming a whole ledger column doesn't block the UI.
+pub fn spawn_export<T, F, I>(name: String, format: ExportFormat, items: F) -> ExportJob
+where
+    T: Exportable,
+    F: FnOnce() -> I + Send + 'static,
+    I: IntoIterator<Item = T>,
+{
+    let export_name = name.clone();
+    let provider = AsyncProvider::new(move |tx| {
+        let result = export_path(&export_name, format)
+            .and_then(|path| write_items(&path, format, items()).map(|count| (path, count)));
+        let _ = tx.blocking_send(result);
+    });
+    ExportJob { name, provider }
+}
+
+/// The exports running in the background, and the outcome of the last one
+/// to finish.
+#[derive(Default)]
+pub struct Exports {
+    jobs: Vec<ExportJob>,
+    last: Option<Result<String, String>>,
+}
+
+impl Exports {
+    pub fn push(&mut self, job: Option<ExportJob>) {
+        self.jobs.extend(job);
+    }
+
+    /// Collects the finished exports, logging their outcome.
+    pub fn poll(&mut self) {
+        let mut last = None;
+        self.jobs.retain_mut(|job| {
+            let result = match job.provider.rx.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Empty) => return true,
+                Err(TryRecvError::Disconnected) => Err(anyhow!("the export stopped")),
+            };
+            last = Some(match result {
+                Ok((path, count)) => {
+                    info!("Exported {} items to {}", count, path.display());
+                    let file = path.file_name().unwrap_or_default().to_string_lossy();
+                    Ok(format!("exported {} items to {}", count, file))
+                }
+                Err(e) => {
+                    error!("Failed to export {}: {}", job.name, e);
+                    Err(format!("failed to export {}", job.name))
+                }
+            });
+            false
+        });
+        if last.is_some() {
+            self.last = last;
+        }
+    }
+
+    /// What the exports are up to, and whether the last one failed.
+    pub fn status(&self) -> Option<Result<String, String>> {
+        if !self.jobs.is_empty() {
+            return Some(Ok(format!("exporting {}...", self.jobs.len())));
+        }
+        self.last.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
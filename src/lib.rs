@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use crate::detection::{AMARU_CHAIN_DB_ENV, AMARU_LEDGER_DB_ENV, detect_amaru_process};
 use amaru_kernel::network::NetworkName;
@@ -14,10 +14,13 @@ pub mod components;
 mod config;
 pub mod controller;
 pub mod detection;
+pub mod diff;
+mod export;
 pub mod logging;
 pub mod metrics;
 pub mod model;
 pub mod otel;
+pub mod query;
 mod states;
 mod store;
 pub mod tui;
@@ -35,25 +38,53 @@ fn prepend_path(opt_base: Option<PathBuf>, name: &str) -> PathBuf {
         .unwrap_or_else(|| PathBuf::from(name))
 }
 
-pub fn open_ledger_db(
-    ledger_db: &Option<PathBuf>,
-    network: &NetworkName,
-) -> Result<ReadOnlyRocksDB> {
+/// The location of the ledger db, given or detected from a running amaru.
+pub fn ledger_db_path(ledger_db: &Option<PathBuf>, network: &NetworkName) -> PathBuf {
     if let Some(path) = ledger_db {
-        ReadOnlyRocksDB::new(RocksDbConfig::new(path.into())).map_err(Into::into)
+        path.clone()
     } else {
         if let Some((cwd, envs)) = detect_amaru_process() {
             let path = envs
                 .get(AMARU_LEDGER_DB_ENV)
                 .cloned()
                 .unwrap_or_else(|| default_db_name("ledger", network));
-            return ReadOnlyRocksDB::new(RocksDbConfig::new(prepend_path(cwd, &path)))
-                .map_err(Into::into);
+            return prepend_path(cwd, &path);
         }
         panic!("No ledger db provided, either through env or args");
     }
 }
 
+pub fn open_ledger_db(
+    ledger_db: &Option<PathBuf>,
+    network: &NetworkName,
+) -> Result<ReadOnlyRocksDB> {
+    ReadOnlyRocksDB::new(RocksDbConfig::new(ledger_db_path(ledger_db, network))).map_err(Into::into)
+}
+
+/// The ledger db opened read-only, with what is needed to re-open it as the
+/// node writes new blocks.
+pub struct LedgerDb {
+    pub db: ReadOnlyRocksDB,
+    pub path: PathBuf,
+    /// How often the db is re-opened, only on demand when `None`.
+    pub refresh_interval: Option<Duration>,
+}
+
+impl LedgerDb {
+    pub fn open(
+        ledger_db: &Option<PathBuf>,
+        network: &NetworkName,
+        refresh_interval: Option<Duration>,
+    ) -> Result<Self> {
+        let path = ledger_db_path(ledger_db, network);
+        Ok(Self {
+            db: ReadOnlyRocksDB::new(RocksDbConfig::new(path.clone()))?,
+            path,
+            refresh_interval,
+        })
+    }
+}
+
 pub fn open_chain_db(chain_db: &Option<PathBuf>, network: &NetworkName) -> Result<ReadOnlyChainDB> {
     if let Some(path) = chain_db {
         RocksDBStore::open_for_readonly(RocksDbConfig::new(path.into())).map_err(Into::into)
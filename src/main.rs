@@ -1,26 +1,76 @@
 use amaru_doctor::{
-    app::App, cli::Cli, model::button::InputEvent, open_chain_db, open_ledger_db,
-    otel::service::OtelCollectorService, tui::Tui,
+    LedgerDb,
+    app::App,
+    cli::{Cli, Command},
+    config::Config,
+    diff,
+    model::button::InputEvent,
+    open_chain_db, open_ledger_db,
+    otel::{recording::TraceRecorder, service::OtelCollectorService},
+    query,
+    tui::Tui,
 };
-use anyhow::Result;
+use anyhow::{Result, bail};
 use clap::Parser;
-use std::sync::mpsc;
+use std::{
+    sync::{Arc, mpsc},
+    time::Duration,
+};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     amaru_doctor::logging::init()?;
 
-    let otel_service = OtelCollectorService::new("0.0.0.0:4317");
-    let otel_handle = otel_service.start();
-
     let args = Cli::parse();
+
+    if let Some(Command::Query { format, query }) = &args.command {
+        let ledger_db = open_ledger_db(&args.ledger_db, &args.network)?;
+        return query::run(Arc::new(ledger_db), query, *format);
+    }
+
+    let compare_ledger_db = args
+        .compare_ledger_db
+        .as_ref()
+        .map(|path| open_ledger_db(&Some(path.clone()), &args.network))
+        .transpose()?;
+
+    if let Some(Command::Diff { list }) = &args.command {
+        let ledger_db = open_ledger_db(&args.ledger_db, &args.network)?;
+        let Some(compare_ledger_db) = &compare_ledger_db else {
+            bail!("The diff command needs a ledger db to compare with, see --compare-ledger-db");
+        };
+        return diff::run(&ledger_db, compare_ledger_db, *list);
+    }
+
+    let config = Config::new()?;
+    let mut otel_service = OtelCollectorService::new(config.otel.clone().override_with(&args));
+    let otel_handle = match &args.replay {
+        Some(path) => otel_service.replay(path.clone(), args.replay_speed),
+        None if !args.open_traces.is_empty() => otel_service.open(args.open_traces.clone()),
+        None => {
+            if let Some(path) = &args.record {
+                otel_service = otel_service.recorder(TraceRecorder::create(path)?);
+            }
+            otel_service.start()
+        }
+    };
+
     let mut tui = Tui::default().mouse(true);
     let (_, dummy_input_events) = mpsc::channel::<InputEvent>();
 
     let mut app: App = App::new(
-        open_ledger_db(&args.ledger_db, &args.network)?,
+        config,
+        LedgerDb::open(
+            &args.ledger_db,
+            &args.network,
+            args.refresh_interval.map(Duration::from_secs),
+        )?,
         open_chain_db(&args.chain_db, &args.network)?,
+        compare_ledger_db,
         otel_handle.snapshot,
+        otel_handle.metrics,
+        otel_handle.status,
+        args.network,
         dummy_input_events,
         tui.get_frame().area(),
     )?;
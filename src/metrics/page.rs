@@ -4,9 +4,10 @@ use crate::{
     metrics::{
         charts::{ChartDatasetConfig, render_chart},
         model::{AmaruMetric, MetricUpdate, NodeMetrics},
-        service,
     },
+    otel::service::CollectorStatusHandle,
     states::{Action, ComponentId},
+    view::collector_status::draw_collector_status,
 };
 use either::Either::Left;
 use ratatui::{
@@ -15,33 +16,29 @@ use ratatui::{
     style::Color,
 };
 use std::{any::Any, collections::HashMap, sync::RwLock};
-use tokio::sync::mpsc::{self, Receiver};
+use tokio::sync::mpsc::Receiver;
 
 pub struct MetricsPageComponent {
     id: ComponentId,
     pub metrics: NodeMetrics,
     update_rx: Receiver<MetricUpdate>,
+    collector_status: CollectorStatusHandle,
     last_layout: RwLock<ComponentLayout>,
     active_focus: RwLock<ComponentId>,
 }
 
 impl MetricsPageComponent {
-    pub fn new(update_rx: Receiver<MetricUpdate>) -> Self {
+    pub fn new(update_rx: Receiver<MetricUpdate>, collector_status: CollectorStatusHandle) -> Self {
         Self {
             id: ComponentId::MetricsPage,
             metrics: NodeMetrics::default(),
             update_rx,
+            collector_status,
             last_layout: RwLock::new(HashMap::new()),
             active_focus: RwLock::new(ComponentId::MetricsPage),
         }
     }
 
-    pub fn new_with_service() -> Self {
-        let (tx, rx) = mpsc::channel(100);
-        service::start(tx);
-        Self::new(rx)
-    }
-
     fn process_update(&mut self, update: MetricUpdate) {
         self.metrics.handle_update(update);
     }
@@ -69,7 +66,16 @@ impl MetricsPageComponent {
         layout
     }
 
-    pub fn render(&self, f: &mut Frame, area: Rect) {
+    pub fn render(&self, f: &mut Frame, mut area: Rect) {
+        // Empty charts don't tell whether amaru is quiet or can't reach us
+        let status = self.collector_status.read().unwrap();
+        if !status.errors.is_empty() {
+            let [status_area, charts_area] =
+                Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+            draw_collector_status(f, status_area, &status);
+            area = charts_area;
+        }
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
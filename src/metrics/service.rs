@@ -1,20 +1,26 @@
-use crate::metrics::model::{AmaruMetric, MetricUpdate};
+use crate::{
+    metrics::model::{AmaruMetric, MetricUpdate},
+    otel::http::decode_request,
+};
 use axum::{
     Router,
     extract::State,
-    http::{HeaderMap, StatusCode},
+    http::HeaderMap,
+    response::{IntoResponse, Response as HttpResponse},
     routing::post,
 };
 use bytes::Bytes;
 use opentelemetry_proto::tonic::{
-    collector::metrics::v1::ExportMetricsServiceRequest,
+    collector::metrics::v1::{
+        ExportMetricsServiceRequest, ExportMetricsServiceResponse,
+        metrics_service_server::MetricsService,
+    },
     metrics::v1::{Metric as OtlpMetric, NumberDataPoint, metric::Data, number_data_point::Value},
 };
-use prost::Message;
-use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::{net::TcpListener, sync::mpsc::Sender};
-use tracing::{error, info, warn};
+use tokio::sync::mpsc::Sender;
+use tonic::{Request, Response, Status};
+use tracing::warn;
 
 fn get_value(dp: &NumberDataPoint) -> Option<f64> {
     match dp.value {
@@ -73,39 +79,55 @@ async fn process_metric(tx: &Arc<Sender<MetricUpdate>>, metric: OtlpMetric) {
     }
 }
 
+async fn process_request(tx: &Arc<Sender<MetricUpdate>>, req: ExportMetricsServiceRequest) {
+    for resource_metrics in req.resource_metrics {
+        for scope_metrics in resource_metrics.scope_metrics {
+            for metric in scope_metrics.metrics {
+                process_metric(tx, metric).await;
+            }
+        }
+    }
+}
+
 async fn handle_metrics(
     State(tx): State<Arc<Sender<MetricUpdate>>>,
-    _headers: HeaderMap,
+    headers: HeaderMap,
     body: Bytes,
-) -> StatusCode {
-    match ExportMetricsServiceRequest::decode(body.as_ref()) {
-        Ok(req) => {
-            for resource_metrics in req.resource_metrics {
-                for scope_metrics in resource_metrics.scope_metrics {
-                    for metric in scope_metrics.metrics {
-                        process_metric(&tx, metric).await;
-                    }
-                }
-            }
-            StatusCode::OK
+) -> HttpResponse {
+    match decode_request(&headers, body).await {
+        Ok((req, encoding)) => {
+            process_request(&tx, req).await;
+            encoding.ok()
         }
-        Err(_) => StatusCode::BAD_REQUEST,
+        Err(status) => status.into_response(),
     }
 }
 
-pub fn start(tx: Sender<MetricUpdate>) {
-    let shared_tx = Arc::new(tx);
+/// The OTLP/HTTP endpoint for metrics.
+pub fn routes(tx: Arc<Sender<MetricUpdate>>) -> Router {
+    Router::new()
+        .route("/v1/metrics", post(handle_metrics))
+        .with_state(tx)
+}
 
-    tokio::spawn(async move {
-        let app = Router::new()
-            .route("/v1/metrics", post(handle_metrics))
-            .with_state(shared_tx);
-        let addr = SocketAddr::from(([0, 0, 0, 0], 4318));
-        info!("Listening on {}", addr);
+/// Accepts metrics over OTLP/gRPC.
+pub struct AmaruMetricsService {
+    tx: Arc<Sender<MetricUpdate>>,
+}
 
-        match axum::serve(TcpListener::bind(addr).await.unwrap(), app).await {
-            Ok(_) => info!("Server exited normally."),
-            Err(e) => error!("Server exited with error: {}", e),
-        }
-    });
+impl AmaruMetricsService {
+    pub fn new(tx: Arc<Sender<MetricUpdate>>) -> Self {
+        Self { tx }
+    }
+}
+
+#[tonic::async_trait]
+impl MetricsService for AmaruMetricsService {
+    async fn export(
+        &self,
+        req: Request<ExportMetricsServiceRequest>,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        process_request(&self.tx, req.into_inner()).await;
+        Ok(Response::new(ExportMetricsServiceResponse::default()))
+    }
 }
@@ -0,0 +1,59 @@
+use crate::ui::to_list_item::AccountItem;
+use amaru_kernel::{DRep, PoolId, StakeCredential};
+use amaru_ledger::store::{
+    ReadStore,
+    columns::{dreps, pools},
+};
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+
+/// An account joined with the pool and DRep it delegates to.
+pub struct AccountDetails {
+    pub account: AccountItem,
+    /// The delegated pool, with its row unless the pool is gone from the ledger.
+    pub pool: Option<(PoolId, Option<pools::Row>)>,
+    /// The delegated DRep, with its row when it is a registered credential.
+    pub drep: Option<(DRep, Option<dreps::Row>)>,
+}
+
+/// The credential a DRep is registered under, `None` for the predefined
+/// abstain and no-confidence DReps.
+pub fn drep_credential(drep: &DRep) -> Option<StakeCredential> {
+    match drep {
+        DRep::Key(hash) => Some(StakeCredential::AddrKeyhash(*hash)),
+        DRep::Script(hash) => Some(StakeCredential::ScriptHash(*hash)),
+        DRep::Abstain | DRep::NoConfidence => None,
+    }
+}
+
+impl AccountDetails {
+    /// Joins the account with its pool and DRep. There is no keyed lookup of
+    /// DReps, so they are scanned: don't call this on the UI thread.
+    pub fn load(db: &ReadOnlyRocksDB, account: &AccountItem) -> Self {
+        let row = &account.1;
+
+        let pool = row.pool.map(|(pool_id, _)| {
+            let pool_row = ReadStore::pool(db, &pool_id).ok().flatten();
+            (pool_id, pool_row)
+        });
+
+        let drep = row.drep.as_ref().map(|(drep, _)| {
+            let drep_row = drep_credential(drep).and_then(|credential| {
+                ReadStore::iter_dreps(db)
+                    .ok()?
+                    .find(|(key, _)| *key == credential)
+                    .map(|(_, row)| row)
+            });
+            (drep.clone(), drep_row)
+        });
+
+        Self {
+            account: account.clone(),
+            pool,
+            drep,
+        }
+    }
+
+    pub fn credential(&self) -> &StakeCredential {
+        &self.account.0
+    }
+}
@@ -0,0 +1,177 @@
+use amaru_kernel::{
+    Bytes, Certificate, Hash, Hasher, MemoizedTransactionOutput, MintedBlock, RawBlock,
+    TransactionInput, to_cbor,
+};
+use amaru_ledger::store::ReadStore;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use anyhow::{Result, anyhow};
+
+/// The parts of a transaction shown on the chain page, decoded from a block
+/// into owned values so they outlive the raw block bytes.
+#[derive(Debug, Clone)]
+pub struct TransactionSummary {
+    pub id: Hash<32>,
+    /// The position of the transaction in its block.
+    pub index: usize,
+    /// False when the transaction failed phase-2 validation.
+    pub is_valid: bool,
+    pub inputs: Vec<TransactionInput>,
+    pub reference_inputs: Vec<TransactionInput>,
+    pub collateral: Vec<TransactionInput>,
+    pub outputs: Vec<MemoizedTransactionOutput>,
+    pub fee: u64,
+    pub certificates: Vec<Certificate>,
+    /// Reward accounts with the withdrawn amount.
+    pub withdrawals: Vec<(Bytes, u64)>,
+    /// Minted (positive) and burnt (negative) assets as (policy, name, amount).
+    pub mint: Vec<(Hash<28>, Bytes, i64)>,
+    pub validity_start: Option<u64>,
+    pub ttl: Option<u64>,
+    pub witnesses: WitnessSummary,
+    /// Whether each output is still in the ledger UTXO set, once checked.
+    pub unspent_outputs: Option<Vec<bool>>,
+}
+
+/// Counts of the witnesses attached to a transaction.
+#[derive(Debug, Clone, Default)]
+pub struct WitnessSummary {
+    pub vkeys: Vec<Bytes>,
+    pub bootstraps: usize,
+    pub native_scripts: usize,
+    pub plutus_scripts: usize,
+    pub plutus_data: usize,
+    pub has_redeemers: bool,
+}
+
+/// A block decoded into its transactions. The raw block is kept around in
+/// case decoding failed, or for exports.
+pub struct DecodedBlock {
+    pub raw: RawBlock,
+    pub transactions: Result<Vec<TransactionSummary>, String>,
+}
+
+impl From<RawBlock> for DecodedBlock {
+    fn from(raw: RawBlock) -> Self {
+        let transactions = decode_transactions(raw.as_ref()).map_err(|e| e.to_string());
+        Self { raw, transactions }
+    }
+}
+
+/// Decodes a block as stored in the chain db, i.e. wrapped with its era tag.
+/// Unwrapped blocks are accepted too.
+fn decode_block(bytes: &[u8]) -> Result<MintedBlock<'_>> {
+    match minicbor::decode::<(u16, MintedBlock<'_>)>(bytes) {
+        Ok((_era, block)) => Ok(block),
+        Err(_) => minicbor::decode::<MintedBlock<'_>>(bytes)
+            .map_err(|e| anyhow!("Unable to decode block: {}", e)),
+    }
+}
+
+fn decode_transactions(bytes: &[u8]) -> Result<Vec<TransactionSummary>> {
+    let block = decode_block(bytes)?;
+    let invalid: Vec<usize> = block
+        .invalid_transactions
+        .as_ref()
+        .map(|ixs| ixs.iter().map(|ix| *ix as usize).collect())
+        .unwrap_or_default();
+
+    block
+        .transaction_bodies
+        .iter()
+        .zip(block.transaction_witness_sets.iter())
+        .enumerate()
+        .map(|(index, (body, witness_set))| {
+            let outputs = body
+                .outputs
+                .iter()
+                .map(|output| {
+                    minicbor::decode::<MemoizedTransactionOutput>(&to_cbor(output))
+                        .map_err(|e| anyhow!("Unable to decode output: {}", e))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let mint = body
+                .mint
+                .iter()
+                .flat_map(|multiasset| multiasset.iter())
+                .flat_map(|(policy, assets)| {
+                    assets
+                        .iter()
+                        .map(move |(name, amount)| (*policy, name.clone(), i64::from(*amount)))
+                })
+                .collect();
+
+            let witnesses = WitnessSummary {
+                vkeys: witness_set
+                    .vkeywitness
+                    .iter()
+                    .flat_map(|ws| ws.iter())
+                    .map(|w| w.vkey.clone())
+                    .collect(),
+                bootstraps: witness_set
+                    .bootstrap_witness
+                    .as_ref()
+                    .map_or(0, |w| w.len()),
+                native_scripts: witness_set.native_script.as_ref().map_or(0, |s| s.len()),
+                plutus_scripts: witness_set.plutus_v1_script.as_ref().map_or(0, |s| s.len())
+                    + witness_set.plutus_v2_script.as_ref().map_or(0, |s| s.len())
+                    + witness_set.plutus_v3_script.as_ref().map_or(0, |s| s.len()),
+                plutus_data: witness_set.plutus_data.as_ref().map_or(0, |d| d.len()),
+                has_redeemers: witness_set.redeemer.is_some(),
+            };
+
+            Ok(TransactionSummary {
+                id: Hasher::<256>::hash(body.raw_cbor()),
+                index,
+                is_valid: !invalid.contains(&index),
+                inputs: body.inputs.iter().cloned().collect(),
+                reference_inputs: body
+                    .reference_inputs
+                    .iter()
+                    .flat_map(|inputs| inputs.iter().cloned())
+                    .collect(),
+                collateral: body
+                    .collateral
+                    .iter()
+                    .flat_map(|inputs| inputs.iter().cloned())
+                    .collect(),
+                outputs,
+                fee: body.fee,
+                certificates: body
+                    .certificates
+                    .iter()
+                    .flat_map(|certs| certs.iter().cloned())
+                    .collect(),
+                withdrawals: body
+                    .withdrawals
+                    .iter()
+                    .flat_map(|ws| {
+                        ws.iter()
+                            .map(|(account, amount)| (account.clone(), *amount))
+                    })
+                    .collect(),
+                mint,
+                validity_start: body.validity_interval_start,
+                ttl: body.ttl,
+                witnesses,
+                unspent_outputs: None,
+            })
+        })
+        .collect()
+}
+
+impl TransactionSummary {
+    /// Checks which outputs of the transaction are still unspent in the ledger.
+    pub fn check_unspent_outputs(&mut self, ledger: &ReadOnlyRocksDB) {
+        let unspent = (0..self.outputs.len())
+            .map(|index| {
+                let input = TransactionInput {
+                    transaction_id: self.id,
+                    index: index as u64,
+                };
+                matches!(ReadStore::utxo(ledger, &input), Ok(Some(_)))
+            })
+            .collect();
+        self.unspent_outputs = Some(unspent);
+    }
+}
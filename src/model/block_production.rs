@@ -0,0 +1,111 @@
+use amaru_kernel::PoolId;
+use amaru_ledger::store::{ReadStore, columns::slots};
+use amaru_slot_arithmetic::EraHistory;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use std::collections::BTreeMap;
+use tracing::warn;
+
+/// The blocks a pool issued, counted per epoch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolBlocks {
+    pub pool_id: PoolId,
+    pub slots: Vec<slots::Key>,
+    /// The epoch of the first count of `per_epoch`.
+    pub first_epoch: u64,
+    /// Blocks issued in each epoch, over the epochs any pool issued blocks in.
+    pub per_epoch: Vec<u64>,
+}
+
+impl PoolBlocks {
+    pub fn total(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+/// Groups issued blocks by pool, over a shared range of epochs. Pools are
+/// ordered by the number of blocks issued, most first.
+pub fn group_by_pool(
+    blocks: impl IntoIterator<Item = (PoolId, slots::Key, u64)>,
+) -> Vec<PoolBlocks> {
+    let mut pools: BTreeMap<PoolId, (Vec<slots::Key>, BTreeMap<u64, u64>)> = BTreeMap::new();
+    let mut epochs: Option<(u64, u64)> = None;
+    for (pool_id, slot, epoch) in blocks {
+        let (slots, per_epoch) = pools.entry(pool_id).or_default();
+        slots.push(slot);
+        *per_epoch.entry(epoch).or_default() += 1;
+        epochs = Some(epochs.map_or((epoch, epoch), |(first, last)| {
+            (first.min(epoch), last.max(epoch))
+        }));
+    }
+    let Some((first_epoch, last_epoch)) = epochs else {
+        return Vec::new();
+    };
+
+    let mut grouped: Vec<_> = pools
+        .into_iter()
+        .map(|(pool_id, (slots, per_epoch))| PoolBlocks {
+            pool_id,
+            slots,
+            first_epoch,
+            per_epoch: (first_epoch..=last_epoch)
+                .map(|epoch| per_epoch.get(&epoch).copied().unwrap_or_default())
+                .collect(),
+        })
+        .collect();
+    grouped.sort_by(|a, b| b.total().cmp(&a.total()));
+    grouped
+}
+
+/// Reads the block issuers and groups them by pool, mapping slots to epochs
+/// with the era history of the network. Blocks whose slot the era history
+/// does not cover are left out, and counted in a warning.
+pub fn load_block_production(
+    db: &ReadOnlyRocksDB,
+    era_history: &EraHistory,
+) -> anyhow::Result<Vec<PoolBlocks>> {
+    let mut skipped = 0;
+    let blocks = ReadStore::iter_block_issuers(db)?.filter_map(|(slot, row)| {
+        match era_history.slot_to_epoch(slot, slot) {
+            Ok(epoch) => Some((row.slot_leader, slot, u64::from(epoch))),
+            Err(e) => {
+                if skipped == 0 {
+                    warn!("No epoch for the block at slot {}: {}", slot, e);
+                }
+                skipped += 1;
+                None
+            }
+        }
+    });
+    let grouped = group_by_pool(blocks);
+    if skipped > 0 {
+        warn!(
+            "Left {} blocks out of the block production, their slot is outside the era history",
+            skipped
+        );
+    }
+    Ok(grouped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_pool() {
+        let a = PoolId::from([1; 28]);
+        let b = PoolId::from([2; 28]);
+        let grouped = group_by_pool([
+            (a, slots::Key::from(10), 1),
+            (b, slots::Key::from(20), 2),
+            (b, slots::Key::from(40), 4),
+            (b, slots::Key::from(41), 4),
+        ]);
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].pool_id, b);
+        assert_eq!(grouped[0].total(), 3);
+        assert_eq!(grouped[0].first_epoch, 1);
+        assert_eq!(grouped[0].per_epoch, vec![0, 1, 0, 2]);
+        assert_eq!(grouped[1].per_epoch, vec![1, 0, 0, 0]);
+    }
+}
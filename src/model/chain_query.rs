@@ -0,0 +1,188 @@
+use crate::model::block::DecodedBlock;
+use amaru_consensus::{BlockHeader, IsHeader, ReadOnlyChainStore};
+use amaru_kernel::Hash;
+use amaru_stores::rocksdb::consensus::ReadOnlyChainDB;
+use anyhow::{Result, anyhow};
+use std::{collections::VecDeque, str::FromStr};
+
+/// A query resolving to one or more headers of the chain db.
+///
+/// Supported forms are a bare header hash, `slot:<slot>`, `height:<height>`,
+/// `tip`, `point:<slot>.<hash>` and `tx:<transaction id>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChainQuery {
+    Hash(Hash<32>),
+    Slot(u64),
+    Height(u64),
+    Tip,
+    Point(u64, Hash<32>),
+    Transaction(Hash<32>),
+}
+
+/// How many blocks back from the tip are scanned when looking up a
+/// transaction.
+pub const TX_SCAN_DEPTH: usize = 2160;
+
+fn parse_hash(s: &str) -> Result<Hash<32>> {
+    Hash::<32>::from_str(s).map_err(|e| anyhow!("Invalid hash {}: {:?}", s, e))
+}
+
+fn parse_number(s: &str) -> Result<u64> {
+    s.parse()
+        .map_err(|e| anyhow!("Invalid number {}: {}", s, e))
+}
+
+impl FromStr for ChainQuery {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("tip") {
+            return Ok(Self::Tip);
+        }
+        match s.split_once(':') {
+            Some(("slot", slot)) => Ok(Self::Slot(parse_number(slot)?)),
+            Some(("height", height)) => Ok(Self::Height(parse_number(height)?)),
+            Some(("point", point)) => {
+                let (slot, hash) = point
+                    .split_once('.')
+                    .ok_or_else(|| anyhow!("Invalid point {}, expected <slot>.<hash>", point))?;
+                Ok(Self::Point(parse_number(slot)?, parse_hash(hash)?))
+            }
+            Some(("tx", id)) => Ok(Self::Transaction(parse_hash(id)?)),
+            Some((prefix, _)) => Err(anyhow!("Unknown query prefix {}", prefix)),
+            None => Ok(Self::Hash(parse_hash(s)?)),
+        }
+    }
+}
+
+impl ChainQuery {
+    /// Resolves the query to the hashes of all matching headers. Several
+    /// headers are returned when forks have headers at the same slot or
+    /// height.
+    pub fn resolve(&self, db: &ReadOnlyChainDB) -> Vec<Hash<32>> {
+        match self {
+            Self::Hash(hash) => load_header(db, hash)
+                .map(|h| h.hash())
+                .into_iter()
+                .collect(),
+            Self::Point(slot, hash) => load_header(db, hash)
+                .filter(|h| h.slot() == *slot)
+                .map(|h| h.hash())
+                .into_iter()
+                .collect(),
+            Self::Tip => vec![ReadOnlyChainStore::<BlockHeader>::get_best_chain_hash(db)],
+            Self::Slot(slot) => find_headers(db, |h| h.slot().cmp(slot)),
+            Self::Height(height) => find_headers(db, |h| h.block_height().cmp(height)),
+            Self::Transaction(id) => find_transaction(db, id).into_iter().collect(),
+        }
+    }
+}
+
+fn load_header(db: &ReadOnlyChainDB, hash: &Hash<32>) -> Option<BlockHeader> {
+    ReadOnlyChainStore::<BlockHeader>::load_header(db, hash)
+}
+
+/// Returns every header stored after the anchor, across all forks.
+pub fn volatile_headers(db: &ReadOnlyChainDB) -> Vec<BlockHeader> {
+    let anchor = ReadOnlyChainStore::<BlockHeader>::get_anchor_hash(db);
+    let mut headers = Vec::new();
+    let mut to_visit = VecDeque::from([anchor]);
+    while let Some(hash) = to_visit.pop_front() {
+        if let Some(header) = load_header(db, &hash) {
+            headers.push(header);
+        }
+        to_visit.extend(ReadOnlyChainStore::<BlockHeader>::get_children(db, &hash));
+    }
+    headers
+}
+
+/// Finds the headers for which `cmp` returns `Equal`. The volatile part of the
+/// chain is searched first, as it may contain forks, before walking the
+/// immutable part back from the anchor.
+fn find_headers<F>(db: &ReadOnlyChainDB, cmp: F) -> Vec<Hash<32>>
+where
+    F: Fn(&BlockHeader) -> std::cmp::Ordering,
+{
+    let matches: Vec<Hash<32>> = volatile_headers(db)
+        .iter()
+        .filter(|h| cmp(h).is_eq())
+        .map(|h| h.hash())
+        .collect();
+    if !matches.is_empty() {
+        return matches;
+    }
+
+    let anchor = ReadOnlyChainStore::<BlockHeader>::get_anchor_hash(db);
+    let mut current = load_header(db, &anchor);
+    while let Some(header) = current {
+        if cmp(&header).is_le() {
+            return if cmp(&header).is_eq() {
+                vec![header.hash()]
+            } else {
+                Vec::new()
+            };
+        }
+        current = header.parent().and_then(|parent| load_header(db, &parent));
+    }
+    Vec::new()
+}
+
+/// Walks the best chain back from the tip, decoding each block, until a block
+/// containing the transaction is found.
+fn find_transaction(db: &ReadOnlyChainDB, id: &Hash<32>) -> Option<Hash<32>> {
+    let mut current = Some(ReadOnlyChainStore::<BlockHeader>::get_best_chain_hash(db));
+    for _ in 0..TX_SCAN_DEPTH {
+        let hash = current?;
+        if let Ok(raw) = ReadOnlyChainStore::<BlockHeader>::load_block(db, &hash)
+            && let Ok(transactions) = DecodedBlock::from(raw).transactions
+            && transactions.iter().any(|tx| tx.id == *id)
+        {
+            return Some(hash);
+        }
+        current = load_header(db, &hash).and_then(|h| h.parent());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "2e6b2226fd74ab0cadc53aaa18759752752bd9b616ea48c0e7b7be77d1af4bf4";
+
+    #[test]
+    fn test_parse_keywords() {
+        assert_eq!(ChainQuery::from_str("tip").unwrap(), ChainQuery::Tip);
+        assert_eq!(
+            ChainQuery::from_str("slot:123").unwrap(),
+            ChainQuery::Slot(123)
+        );
+        assert_eq!(
+            ChainQuery::from_str("height:456").unwrap(),
+            ChainQuery::Height(456)
+        );
+    }
+
+    #[test]
+    fn test_parse_hash_and_point() {
+        let hash = Hash::<32>::from_str(HASH).unwrap();
+        assert_eq!(ChainQuery::from_str(HASH).unwrap(), ChainQuery::Hash(hash));
+        assert_eq!(
+            ChainQuery::from_str(&format!("point:42.{HASH}")).unwrap(),
+            ChainQuery::Point(42, hash)
+        );
+        assert_eq!(
+            ChainQuery::from_str(&format!("tx:{HASH}")).unwrap(),
+            ChainQuery::Transaction(hash)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(ChainQuery::from_str("slot:abc").is_err());
+        assert!(ChainQuery::from_str("point:42").is_err());
+        assert!(ChainQuery::from_str("epoch:1").is_err());
+        assert!(ChainQuery::from_str("not-a-hash").is_err());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::model::chain_query::volatile_headers;
+use amaru_consensus::{BlockHeader, IsHeader, ReadOnlyChainStore};
+use amaru_kernel::Hash;
+use amaru_stores::rocksdb::consensus::ReadOnlyChainDB;
+use std::collections::{HashMap, HashSet};
+
+/// A single rendered line of the fork tree.
+#[derive(Debug, Clone)]
+pub struct ForkTreeLine {
+    /// The ASCII art drawn before the header, e.g. `│ ├─● `.
+    pub prefix: String,
+    pub hash: Hash<32>,
+    pub height: u64,
+    pub slot: u64,
+    pub on_best_chain: bool,
+}
+
+#[derive(Debug)]
+struct ForkTreeNode {
+    hash: Hash<32>,
+    parent: Option<Hash<32>>,
+    height: u64,
+    slot: u64,
+    children: Vec<usize>,
+}
+
+/// The headers stored near the tip of the chain db, arranged as a tree so that
+/// competing forks and rollbacks can be seen.
+#[derive(Debug, Default)]
+pub struct ForkTree {
+    nodes: Vec<ForkTreeNode>,
+    roots: Vec<usize>,
+    best_chain: HashSet<Hash<32>>,
+}
+
+impl ForkTree {
+    /// Loads the headers at most `depth` blocks below the highest stored
+    /// header.
+    pub fn load(db: &ReadOnlyChainDB, depth: u64) -> Self {
+        let headers = volatile_headers(db);
+        let max_height = headers.iter().map(|h| h.block_height()).max().unwrap_or(0);
+        let min_height = max_height.saturating_sub(depth);
+        let nodes = headers
+            .iter()
+            .filter(|h| h.block_height() >= min_height)
+            .map(|h| ForkTreeNode {
+                hash: h.hash(),
+                parent: h.parent(),
+                height: h.block_height(),
+                slot: h.slot(),
+                children: Vec::new(),
+            })
+            .collect();
+        Self::build(
+            nodes,
+            ReadOnlyChainStore::<BlockHeader>::get_best_chain_hash(db),
+        )
+    }
+
+    /// Links the nodes to their parent, those whose parent isn't among them
+    /// becoming roots, and marks the chain ending at `best_tip`.
+    fn build(mut nodes: Vec<ForkTreeNode>, best_tip: Hash<32>) -> Self {
+        let index: HashMap<Hash<32>, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.hash, i))
+            .collect();
+        let parents: Vec<Option<usize>> = nodes
+            .iter()
+            .map(|node| node.parent.and_then(|p| index.get(&p).copied()))
+            .collect();
+        let mut roots = Vec::new();
+        for (i, parent) in parents.into_iter().enumerate() {
+            match parent {
+                Some(parent) => nodes[parent].children.push(i),
+                None => roots.push(i),
+            }
+        }
+
+        let mut best_chain = HashSet::new();
+        let mut current = Some(best_tip);
+        while let Some(hash) = current.filter(|h| index.contains_key(h)) {
+            best_chain.insert(hash);
+            current = nodes[index[&hash]].parent;
+        }
+
+        Self {
+            nodes,
+            roots,
+            best_chain,
+        }
+    }
+
+    /// Flattens the tree, oldest header first. The main line of each branch
+    /// continues in the same column while forks are drawn indented below
+    /// their parent.
+    pub fn lines(&self) -> Vec<ForkTreeLine> {
+        let mut lines = Vec::with_capacity(self.nodes.len());
+        for &root in &self.roots {
+            self.push_branch(root, String::new(), String::new(), &mut lines);
+        }
+        lines
+    }
+
+    fn push_branch(
+        &self,
+        mut idx: usize,
+        first_prefix: String,
+        prefix: String,
+        lines: &mut Vec<ForkTreeLine>,
+    ) {
+        let mut marker = first_prefix;
+        loop {
+            let node = &self.nodes[idx];
+            lines.push(ForkTreeLine {
+                prefix: format!("{marker}● "),
+                hash: node.hash,
+                height: node.height,
+                slot: node.slot,
+                on_best_chain: self.best_chain.contains(&node.hash),
+            });
+
+            // Follow the best chain if it goes through this node, otherwise
+            // the first stored child.
+            let main = node
+                .children
+                .iter()
+                .position(|c| self.best_chain.contains(&self.nodes[*c].hash))
+                .unwrap_or(0);
+            for (i, &fork) in node.children.iter().enumerate() {
+                if i != main {
+                    self.push_branch(fork, format!("{prefix}├─"), format!("{prefix}│ "), lines);
+                }
+            }
+
+            match node.children.get(main) {
+                Some(&next) => {
+                    idx = next;
+                    marker = prefix.clone();
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(n: u8) -> Hash<32> {
+        Hash::new([n; 32])
+    }
+
+    fn node(n: u8, parent: Option<u8>) -> ForkTreeNode {
+        ForkTreeNode {
+            hash: hash(n),
+            parent: parent.map(hash),
+            height: n as u64,
+            slot: n as u64 * 20,
+            children: Vec::new(),
+        }
+    }
+
+    fn prefixes(lines: &[ForkTreeLine]) -> Vec<(u64, &str, bool)> {
+        lines
+            .iter()
+            .map(|l| (l.height, l.prefix.as_str(), l.on_best_chain))
+            .collect()
+    }
+
+    #[test]
+    fn test_single_chain() {
+        let tree = ForkTree::build(
+            vec![node(1, Some(0)), node(2, Some(1)), node(3, Some(2))],
+            hash(3),
+        );
+        assert_eq!(
+            prefixes(&tree.lines()),
+            vec![(1, "● ", true), (2, "● ", true), (3, "● ", true)]
+        );
+    }
+
+    #[test]
+    fn test_branching() {
+        // 1 ─ 2 ─ 3 ─ 5 is the best chain, 2 ─ 4 and 3 ─ 6 ─ 7 are forks.
+        let tree = ForkTree::build(
+            vec![
+                node(1, None),
+                node(2, Some(1)),
+                node(4, Some(2)),
+                node(3, Some(2)),
+                node(6, Some(3)),
+                node(7, Some(6)),
+                node(5, Some(3)),
+            ],
+            hash(5),
+        );
+        assert_eq!(
+            prefixes(&tree.lines()),
+            vec![
+                (1, "● ", true),
+                (2, "● ", true),
+                (4, "├─● ", false),
+                (3, "● ", true),
+                (6, "├─● ", false),
+                (7, "│ ● ", false),
+                (5, "● ", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_fork_without_best_chain() {
+        // Without the best tip among the nodes, the first child is followed.
+        let tree = ForkTree::build(
+            vec![
+                node(1, None),
+                node(2, Some(1)),
+                node(3, Some(1)),
+                node(4, Some(3)),
+                node(5, Some(3)),
+            ],
+            hash(9),
+        );
+        assert_eq!(
+            prefixes(&tree.lines()),
+            vec![
+                (1, "● ", false),
+                (3, "├─● ", false),
+                (5, "│ ├─● ", false),
+                (4, "│ ● ", false),
+                (2, "● ", false),
+            ]
+        );
+    }
+}
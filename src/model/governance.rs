@@ -0,0 +1,391 @@
+use crate::{model::stake::StakeDistribution, ui::to_list_item::ProposalItem};
+use amaru_kernel::{
+    ComparableProposalId, GovAction, RationalNumber, StakeCredential, Vote, Voter,
+    protocol_parameters::ProtocolParameters,
+};
+use amaru_ledger::store::{ReadStore, Snapshot};
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use std::collections::BTreeMap;
+
+/// The hot credential of a committee voter, `None` for other voters.
+pub fn committee_hot_credential(voter: &Voter) -> Option<StakeCredential> {
+    match voter {
+        Voter::ConstitutionalCommitteeKey(hash) => Some(StakeCredential::AddrKeyhash(*hash)),
+        Voter::ConstitutionalCommitteeScript(hash) => Some(StakeCredential::ScriptHash(*hash)),
+        _ => None,
+    }
+}
+
+/// Votes for, against and abstaining on a proposal, weighted by stake, or
+/// one per member for the committee.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Tally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+impl Tally {
+    pub fn add(&mut self, vote: &Vote, weight: u64) {
+        match vote {
+            Vote::Yes => self.yes += weight,
+            Vote::No => self.no += weight,
+            Vote::Abstain => self.abstain += weight,
+        }
+    }
+
+    /// The share of yes votes in `total`, the stake that could have voted,
+    /// once abstentions are taken out. Stake that didn't vote counts as no.
+    pub fn yes_ratio(&self, total: u64) -> Option<f64> {
+        let active = total.saturating_sub(self.abstain);
+        (active > 0).then(|| self.yes as f64 / active as f64)
+    }
+}
+
+pub fn ratio(number: &RationalNumber) -> f64 {
+    number.numerator as f64 / number.denominator as f64
+}
+
+/// The thresholds a proposal must reach to be ratified, `None` for the
+/// groups that don't vote on its kind of action.
+#[derive(Clone, Debug, Default)]
+pub struct Thresholds {
+    pub drep: Option<RationalNumber>,
+    pub spo: Option<RationalNumber>,
+    pub committee: bool,
+}
+
+impl Thresholds {
+    /// Parameter changes are checked against the highest of the DRep group
+    /// thresholds rather than the groups the update touches, and the
+    /// security group vote of pools is not required.
+    fn of(action: &GovAction, params: &ProtocolParameters) -> Self {
+        let drep = &params.drep_voting_thresholds;
+        let pool = &params.pool_voting_thresholds;
+        match action {
+            GovAction::NoConfidence(..) => Self {
+                drep: Some(drep.motion_no_confidence.clone()),
+                spo: Some(pool.motion_no_confidence.clone()),
+                committee: false,
+            },
+            GovAction::UpdateCommittee(..) => Self {
+                drep: Some(drep.committee_normal.clone()),
+                spo: Some(pool.committee_normal.clone()),
+                committee: false,
+            },
+            GovAction::NewConstitution(..) => Self {
+                drep: Some(drep.update_constitution.clone()),
+                spo: None,
+                committee: true,
+            },
+            GovAction::HardForkInitiation(..) => Self {
+                drep: Some(drep.hard_fork_initiation.clone()),
+                spo: Some(pool.hard_fork_initiation.clone()),
+                committee: true,
+            },
+            GovAction::ParameterChange(..) => Self {
+                drep: [
+                    &drep.pp_network_group,
+                    &drep.pp_economic_group,
+                    &drep.pp_technical_group,
+                    &drep.pp_governance_group,
+                ]
+                .into_iter()
+                .max_by(|a, b| ratio(a).total_cmp(&ratio(b)))
+                .cloned(),
+                spo: None,
+                committee: true,
+            },
+            GovAction::TreasuryWithdrawals(..) => Self {
+                drep: Some(drep.treasury_withdrawal.clone()),
+                spo: None,
+                committee: true,
+            },
+            GovAction::Information => Self::default(),
+        }
+    }
+}
+
+/// The DRep, pool and committee tallies of the votes on an action.
+fn tally_votes(
+    votes: &[(Voter, Vote)],
+    stake: &StakeDistribution,
+    action: &GovAction,
+) -> (Tally, Tally, Tally) {
+    let mut drep = Tally::default();
+    let mut spo = Tally::default();
+    let mut committee = Tally::default();
+    for (voter, vote) in votes {
+        match voter {
+            Voter::DRepKey(hash) => {
+                let credential = StakeCredential::AddrKeyhash(*hash);
+                drep.add(
+                    vote,
+                    stake.dreps.get(&credential).copied().unwrap_or_default(),
+                );
+            }
+            Voter::DRepScript(hash) => {
+                let credential = StakeCredential::ScriptHash(*hash);
+                drep.add(
+                    vote,
+                    stake.dreps.get(&credential).copied().unwrap_or_default(),
+                );
+            }
+            Voter::StakePoolKey(pool_id) => {
+                spo.add(vote, stake.pools.get(pool_id).copied().unwrap_or_default());
+            }
+            Voter::ConstitutionalCommitteeKey(_) | Voter::ConstitutionalCommitteeScript(_) => {
+                committee.add(vote, 1)
+            }
+        }
+    }
+    // Stake delegated to no-confidence votes yes on no-confidence motions,
+    // and no on everything else.
+    if matches!(action, GovAction::NoConfidence(..)) {
+        drep.yes += stake.always_no_confidence;
+    }
+    (drep, spo, committee)
+}
+
+/// A proposal joined with its votes, tallied against the stake of the DReps
+/// and pools.
+#[derive(Clone, Debug)]
+pub struct ProposalTally {
+    pub proposal: ProposalItem,
+    pub votes: Vec<(Voter, Vote)>,
+    pub drep: Tally,
+    pub drep_total: u64,
+    pub spo: Tally,
+    pub spo_total: u64,
+    pub committee: Tally,
+    pub thresholds: Thresholds,
+    /// Whether the proposal outlived its validity at the ledger epoch.
+    pub expired: bool,
+}
+
+fn met(tally: Option<f64>, threshold: Option<&RationalNumber>) -> Option<bool> {
+    threshold.map(|threshold| tally.unwrap_or_default() >= ratio(threshold))
+}
+
+impl ProposalTally {
+    fn new(
+        proposal: ProposalItem,
+        votes: Vec<(Voter, Vote)>,
+        stake: &StakeDistribution,
+        params: &ProtocolParameters,
+        epoch: u64,
+    ) -> Self {
+        let action = &proposal.1.proposal.gov_action;
+        let (drep, spo, committee) = tally_votes(&votes, stake, action);
+
+        Self {
+            thresholds: Thresholds::of(action, params),
+            expired: u64::from(proposal.1.valid_until) < epoch,
+            proposal,
+            votes,
+            drep,
+            drep_total: stake.total_drep_stake() + stake.always_no_confidence,
+            spo,
+            spo_total: stake.total_pool_stake(),
+            committee,
+        }
+    }
+
+    pub fn id(&self) -> &ComparableProposalId {
+        &self.proposal.0
+    }
+
+    pub fn drep_ratio(&self) -> Option<f64> {
+        self.drep.yes_ratio(self.drep_total)
+    }
+
+    pub fn spo_ratio(&self) -> Option<f64> {
+        self.spo.yes_ratio(self.spo_total)
+    }
+
+    pub fn drep_met(&self) -> Option<bool> {
+        met(self.drep_ratio(), self.thresholds.drep.as_ref())
+    }
+
+    pub fn spo_met(&self) -> Option<bool> {
+        met(self.spo_ratio(), self.thresholds.spo.as_ref())
+    }
+
+    /// Whether the DRep and pool thresholds are met. The committee vote is
+    /// not checked, its quorum is not part of the protocol parameters.
+    pub fn thresholds_met(&self) -> bool {
+        (self.thresholds.drep.is_some() || self.thresholds.spo.is_some())
+            && self.drep_met().unwrap_or(true)
+            && self.spo_met().unwrap_or(true)
+    }
+}
+
+/// Tallies every proposal of the ledger, sending each to `emit` until it
+/// returns false.
+pub fn load_tallies(
+    db: &ReadOnlyRocksDB,
+    stake: &StakeDistribution,
+    mut emit: impl FnMut(ProposalTally) -> bool,
+) -> anyhow::Result<()> {
+    let params = ReadStore::protocol_parameters(db)?;
+    let epoch = u64::from(Snapshot::epoch(db));
+
+    let mut votes: BTreeMap<ComparableProposalId, Vec<(Voter, Vote)>> = BTreeMap::new();
+    for (key, row) in ReadStore::iter_votes(db)? {
+        votes
+            .entry(key.proposal)
+            .or_default()
+            .push((key.voter, row.vote));
+    }
+
+    for proposal in ReadStore::iter_proposals(db)? {
+        let proposal_votes = votes.remove(&proposal.0).unwrap_or_default();
+        if !emit(ProposalTally::new(
+            proposal,
+            proposal_votes,
+            stake,
+            &params,
+            epoch,
+        )) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amaru_kernel::{
+        Hash, Nullable, PoolId, ProtocolParamUpdate,
+        protocol_parameters::PREPROD_INITIAL_PROTOCOL_PARAMETERS,
+    };
+    use std::collections::HashMap;
+
+    fn ratios(thresholds: &Thresholds) -> (Option<f64>, Option<f64>, bool) {
+        (
+            thresholds.drep.as_ref().map(ratio),
+            thresholds.spo.as_ref().map(ratio),
+            thresholds.committee,
+        )
+    }
+
+    #[test]
+    fn test_thresholds_of() {
+        let params = &*PREPROD_INITIAL_PROTOCOL_PARAMETERS;
+        let drep = &params.drep_voting_thresholds;
+        let pool = &params.pool_voting_thresholds;
+
+        assert_eq!(
+            ratios(&Thresholds::of(&GovAction::Information, params)),
+            (None, None, false)
+        );
+        assert_eq!(
+            ratios(&Thresholds::of(
+                &GovAction::NoConfidence(Nullable::Null),
+                params
+            )),
+            (
+                Some(ratio(&drep.motion_no_confidence)),
+                Some(ratio(&pool.motion_no_confidence)),
+                false
+            )
+        );
+        assert_eq!(
+            ratios(&Thresholds::of(
+                &GovAction::HardForkInitiation(Nullable::Null, (10, 0)),
+                params
+            )),
+            (
+                Some(ratio(&drep.hard_fork_initiation)),
+                Some(ratio(&pool.hard_fork_initiation)),
+                true
+            )
+        );
+
+        // An empty update, the highest group threshold applies
+        let update: ProtocolParamUpdate = minicbor::decode(&[0xa0]).unwrap();
+        let highest = [
+            &drep.pp_network_group,
+            &drep.pp_economic_group,
+            &drep.pp_technical_group,
+            &drep.pp_governance_group,
+        ]
+        .map(ratio)
+        .into_iter()
+        .fold(0.0, f64::max);
+        assert_eq!(
+            ratios(&Thresholds::of(
+                &GovAction::ParameterChange(Nullable::Null, Box::new(update), Nullable::Null),
+                params
+            )),
+            (Some(highest), None, true)
+        );
+    }
+
+    #[test]
+    fn test_tally_votes() {
+        let key = |n: u8| Hash::<28>::from([n; 28]);
+        let stake = StakeDistribution {
+            pools: HashMap::from([(PoolId::from([7; 28]), 300)]),
+            dreps: HashMap::from([
+                (StakeCredential::AddrKeyhash(key(1)), 100),
+                (StakeCredential::ScriptHash(key(2)), 50),
+            ]),
+            always_abstain: 10,
+            always_no_confidence: 25,
+        };
+        let votes = vec![
+            (Voter::DRepKey(key(1)), Vote::Yes),
+            (Voter::DRepScript(key(2)), Vote::No),
+            // Not delegated to, weighs nothing
+            (Voter::DRepKey(key(3)), Vote::Yes),
+            (Voter::StakePoolKey(PoolId::from([7; 28])), Vote::Abstain),
+            (Voter::ConstitutionalCommitteeKey(key(4)), Vote::Yes),
+            (Voter::ConstitutionalCommitteeScript(key(5)), Vote::No),
+        ];
+
+        let (drep, spo, committee) = tally_votes(&votes, &stake, &GovAction::Information);
+        assert_eq!(
+            drep,
+            Tally {
+                yes: 100,
+                no: 50,
+                abstain: 0
+            }
+        );
+        assert_eq!(
+            spo,
+            Tally {
+                yes: 0,
+                no: 0,
+                abstain: 300
+            }
+        );
+        assert_eq!(
+            committee,
+            Tally {
+                yes: 1,
+                no: 1,
+                abstain: 0
+            }
+        );
+
+        // The no-confidence stake backs no-confidence motions
+        let (drep, _, _) = tally_votes(&votes, &stake, &GovAction::NoConfidence(Nullable::Null));
+        assert_eq!(drep.yes, 125);
+    }
+
+    #[test]
+    fn test_yes_ratio() {
+        let mut tally = Tally::default();
+        assert_eq!(tally.yes_ratio(0), None);
+
+        tally.add(&Vote::Yes, 30);
+        tally.add(&Vote::No, 10);
+        tally.add(&Vote::Abstain, 20);
+        // 40 didn't vote and count as no
+        assert_eq!(tally.yes_ratio(100), Some(0.375));
+        assert_eq!(tally.yes_ratio(20), None);
+    }
+}
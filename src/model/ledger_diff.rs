@@ -0,0 +1,354 @@
+use crate::{
+    model::proposal::ProposalEntry,
+    ui::{
+        to_list_item::{
+            AccountItem, BlockIssuerItem, CommitteeMemberItem, DRepItem, PoolItem, ProposalItem,
+            UtxoItem,
+        },
+        to_table_row::ToTableRow,
+    },
+};
+use amaru_kernel::to_cbor;
+use amaru_ledger::store::ReadStore;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use serde::Serialize;
+use std::cmp::Ordering;
+use strum::{Display, EnumIter};
+
+/// The ledger columns compared by a diff.
+#[derive(Clone, Copy, Debug, Display, EnumIter, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum LedgerColumn {
+    Accounts,
+    Pools,
+    DReps,
+    Proposals,
+    #[serde(rename = "block_issuers")]
+    #[strum(serialize = "block_issuers")]
+    BlockIssuers,
+    Committee,
+    Utxos,
+}
+
+/// The items of a compared column.
+pub trait ColumnItem: Sized {
+    const COLUMN: LedgerColumn;
+
+    /// The item held by `entry`, `None` when it belongs to another column.
+    fn from_entry(entry: DiffEntry) -> Option<Self>;
+}
+
+macro_rules! impl_column_item {
+    ($item:ty, $column:ident, $variant:ident) => {
+        impl ColumnItem for $item {
+            const COLUMN: LedgerColumn = LedgerColumn::$column;
+
+            fn from_entry(entry: DiffEntry) -> Option<Self> {
+                match entry {
+                    DiffEntry::$variant(item) => Some(item),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_column_item!(AccountItem, Accounts, Account);
+impl_column_item!(PoolItem, Pools, Pool);
+impl_column_item!(DRepItem, DReps, DRep);
+impl_column_item!(ProposalItem, Proposals, Proposal);
+impl_column_item!(BlockIssuerItem, BlockIssuers, BlockIssuer);
+impl_column_item!(CommitteeMemberItem, Committee, CommitteeMember);
+impl_column_item!(UtxoItem, Utxos, Utxo);
+
+impl ColumnItem for ProposalEntry {
+    const COLUMN: LedgerColumn = LedgerColumn::Proposals;
+
+    fn from_entry(entry: DiffEntry) -> Option<Self> {
+        ProposalItem::from_entry(entry).map(ProposalEntry::removed)
+    }
+}
+
+/// An entry of one of the compared columns.
+#[derive(Clone)]
+pub enum DiffEntry {
+    Account(AccountItem),
+    Pool(PoolItem),
+    DRep(DRepItem),
+    Proposal(ProposalItem),
+    BlockIssuer(BlockIssuerItem),
+    CommitteeMember(CommitteeMemberItem),
+    Utxo(UtxoItem),
+}
+
+impl DiffEntry {
+    /// The key of the entry, as shown in the first column of its table.
+    pub fn identifier(&self) -> String {
+        match self {
+            Self::Account(item) => item.identifier(),
+            Self::Pool(item) => item.identifier(),
+            Self::DRep(item) => item.identifier(),
+            Self::Proposal(item) => item.identifier(),
+            Self::BlockIssuer(item) => item.identifier(),
+            Self::CommitteeMember(item) => item.identifier(),
+            Self::Utxo(item) => item.identifier(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffKind {
+    /// Only in the ledger being inspected.
+    Removed,
+    /// Only in the ledger it is compared with.
+    Added,
+    Changed,
+}
+
+/// An entry that differs between the two ledgers, with its value on each side.
+#[derive(Clone)]
+pub struct LedgerDifference {
+    pub column: LedgerColumn,
+    pub left: Option<DiffEntry>,
+    pub right: Option<DiffEntry>,
+}
+
+impl LedgerDifference {
+    pub fn kind(&self) -> DiffKind {
+        match (&self.left, &self.right) {
+            (Some(_), None) => DiffKind::Removed,
+            (None, Some(_)) => DiffKind::Added,
+            _ => DiffKind::Changed,
+        }
+    }
+
+    pub fn identifier(&self) -> String {
+        self.left
+            .as_ref()
+            .or(self.right.as_ref())
+            .map(DiffEntry::identifier)
+            .unwrap_or_default()
+    }
+}
+
+/// Walks two iterators sorted by `cmp`, calling `emit` with the items only one
+/// side has and with the pairs that are not `same`. Stops early once `emit`
+/// returns false, in which case it returns false too.
+pub fn merge_diff<T>(
+    left: impl IntoIterator<Item = T>,
+    right: impl IntoIterator<Item = T>,
+    cmp: impl Fn(&T, &T) -> Ordering,
+    same: impl Fn(&T, &T) -> bool,
+    mut emit: impl FnMut(Option<T>, Option<T>) -> bool,
+) -> bool {
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    loop {
+        let keep_going = match (left.peek(), right.peek()) {
+            (None, None) => return true,
+            (Some(_), None) => emit(left.next(), None),
+            (None, Some(_)) => emit(None, right.next()),
+            (Some(l), Some(r)) => match cmp(l, r) {
+                Ordering::Less => emit(left.next(), None),
+                Ordering::Greater => emit(None, right.next()),
+                Ordering::Equal => {
+                    let (l, r) = (left.next(), right.next());
+                    match (&l, &r) {
+                        (Some(l), Some(r)) if same(l, r) => true,
+                        _ => emit(l, r),
+                    }
+                }
+            },
+        };
+        if !keep_going {
+            return false;
+        }
+    }
+}
+
+/// Diffs two columns of entries in the order RocksDB iterates them: it sorts
+/// keys bytewise, so both columns come in the order of their CBOR encoded
+/// keys. Each key is encoded once, and values only when the keys match.
+fn diff_encoded<K, V>(
+    left: impl Iterator<Item = (K, V)>,
+    right: impl Iterator<Item = (K, V)>,
+    mut emit: impl FnMut(Option<(K, V)>, Option<(K, V)>) -> bool,
+) -> bool
+where
+    K: minicbor::Encode<()>,
+    V: minicbor::Encode<()>,
+{
+    let encoded = |(key, value): (K, V)| (to_cbor(&key), (key, value));
+    merge_diff(
+        left.map(encoded),
+        right.map(encoded),
+        |(a, _), (b, _)| a.cmp(b),
+        |(_, (_, a)), (_, (_, b))| to_cbor(a) == to_cbor(b),
+        |l, r| emit(l.map(|(_, entry)| entry), r.map(|(_, entry)| entry)),
+    )
+}
+
+/// Diffs a column of both ledgers.
+fn diff_column<K, V>(
+    column: LedgerColumn,
+    left: impl Iterator<Item = (K, V)>,
+    right: impl Iterator<Item = (K, V)>,
+    wrap: fn((K, V)) -> DiffEntry,
+    emit: &mut impl FnMut(LedgerDifference) -> bool,
+) -> bool
+where
+    K: minicbor::Encode<()>,
+    V: minicbor::Encode<()>,
+{
+    diff_encoded(left, right, |l, r| {
+        emit(LedgerDifference {
+            column,
+            left: l.map(wrap),
+            right: r.map(wrap),
+        })
+    })
+}
+
+/// Compares `columns` of two ledgers, column by column, sending every
+/// difference to `emit` until it returns false.
+pub fn diff_ledgers(
+    left: &ReadOnlyRocksDB,
+    right: &ReadOnlyRocksDB,
+    columns: &[LedgerColumn],
+    mut emit: impl FnMut(LedgerDifference) -> bool,
+) -> anyhow::Result<()> {
+    for &column in columns {
+        let done = match column {
+            LedgerColumn::Accounts => diff_column(
+                column,
+                ReadStore::iter_accounts(left)?,
+                ReadStore::iter_accounts(right)?,
+                DiffEntry::Account,
+                &mut emit,
+            ),
+            LedgerColumn::Pools => diff_column(
+                column,
+                ReadStore::iter_pools(left)?,
+                ReadStore::iter_pools(right)?,
+                DiffEntry::Pool,
+                &mut emit,
+            ),
+            LedgerColumn::DReps => diff_column(
+                column,
+                ReadStore::iter_dreps(left)?,
+                ReadStore::iter_dreps(right)?,
+                DiffEntry::DRep,
+                &mut emit,
+            ),
+            LedgerColumn::Proposals => diff_column(
+                column,
+                ReadStore::iter_proposals(left)?,
+                ReadStore::iter_proposals(right)?,
+                DiffEntry::Proposal,
+                &mut emit,
+            ),
+            LedgerColumn::BlockIssuers => diff_column(
+                column,
+                ReadStore::iter_block_issuers(left)?,
+                ReadStore::iter_block_issuers(right)?,
+                DiffEntry::BlockIssuer,
+                &mut emit,
+            ),
+            LedgerColumn::Committee => diff_column(
+                column,
+                ReadStore::iter_cc_members(left)?,
+                ReadStore::iter_cc_members(right)?,
+                DiffEntry::CommitteeMember,
+                &mut emit,
+            ),
+            LedgerColumn::Utxos => diff_column(
+                column,
+                ReadStore::iter_utxos(left)?,
+                ReadStore::iter_utxos(right)?,
+                DiffEntry::Utxo,
+                &mut emit,
+            ),
+        };
+        // A column is only diffed while `emit` keeps accepting differences
+        if !done {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(left: &[(u8, char)], right: &[(u8, char)]) -> Vec<(Option<u8>, Option<u8>)> {
+        let mut out = Vec::new();
+        merge_diff(
+            left.iter().copied(),
+            right.iter().copied(),
+            |a, b| a.0.cmp(&b.0),
+            |a, b| a.1 == b.1,
+            |l, r| {
+                out.push((l.map(|(k, _)| k), r.map(|(k, _)| k)));
+                true
+            },
+        );
+        out
+    }
+
+    #[test]
+    fn test_merge_diff() {
+        assert_eq!(
+            diff(
+                &[(1, 'a'), (2, 'b'), (4, 'd')],
+                &[(2, 'b'), (3, 'c'), (4, 'x'), (5, 'e')]
+            ),
+            vec![
+                (Some(1), None),
+                (None, Some(3)),
+                (Some(4), Some(4)),
+                (None, Some(5)),
+            ]
+        );
+        assert!(diff(&[(1, 'a')], &[(1, 'a')]).is_empty());
+    }
+
+    #[test]
+    fn test_diff_encoded_follows_encoded_key_order() {
+        // Shorter strings encode first, whatever their characters
+        assert!(to_cbor(&"b") < to_cbor(&"aa"));
+
+        let mut out = Vec::new();
+        let done = diff_encoded(
+            [("b", 1), ("aa", 2)].into_iter(),
+            [("b", 1), ("aa", 3), ("ab", 4)].into_iter(),
+            |l, r| {
+                out.push((l, r));
+                true
+            },
+        );
+        assert!(done);
+        assert_eq!(
+            out,
+            vec![(Some(("aa", 2)), Some(("aa", 3))), (None, Some(("ab", 4))),]
+        );
+    }
+
+    #[test]
+    fn test_merge_diff_stops() {
+        let mut seen = 0;
+        let done = merge_diff(
+            [1, 2, 3],
+            [],
+            |a: &i32, b| a.cmp(b),
+            |a, b| a == b,
+            |_, _| {
+                seen += 1;
+                seen < 2
+            },
+        );
+        assert!(!done);
+        assert_eq!(seen, 2);
+    }
+}
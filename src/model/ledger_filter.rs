@@ -0,0 +1,580 @@
+use crate::{
+    model::proposal::ProposalEntry,
+    ui::{
+        to_list_item::{
+            AccountItem, BlockIssuerItem, CommitteeMemberItem, DRepItem, PoolItem, ProposalItem,
+            UtxoItem,
+        },
+        to_rich::{
+            account::StakeCredentialDisplay,
+            proposal::{ComparableProposalIdDisplay, gov_action_type},
+            utxo::TransactionInputDisplay,
+        },
+        to_table_row::drep_cell,
+    },
+};
+use amaru_kernel::{DRep, MemoizedDatum, Value};
+use anyhow::{anyhow, bail};
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// The value of a named field of a ledger entity, used to filter and sort
+/// lists.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Int(u64),
+    Ratio(f64),
+    Bool(bool),
+    Text(String),
+}
+
+impl FieldValue {
+    /// Compares the value to an operand typed by the user, `None` when the
+    /// operand can't be read as a value of the same type.
+    fn compare_to(&self, operand: &str) -> Option<Ordering> {
+        match self {
+            Self::Int(value) => operand
+                .replace('_', "")
+                .parse::<u64>()
+                .ok()
+                .map(|operand| value.cmp(&operand)),
+            Self::Ratio(value) => {
+                parse_ratio(operand).and_then(|operand| value.partial_cmp(&operand))
+            }
+            Self::Bool(value) => operand
+                .parse::<bool>()
+                .ok()
+                .map(|operand| value.cmp(&operand)),
+            Self::Text(value) => Some(value.as_str().cmp(operand)),
+        }
+    }
+
+    fn sort_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            (Self::Ratio(a), Self::Ratio(b)) => a.total_cmp(b),
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// A DRep as shown in the tables, so it can be typed in a filter.
+fn drep_field(drep: &DRep) -> FieldValue {
+    FieldValue::Text(drep_cell(drep))
+}
+
+/// Reads `0.05` as well as `1/20`.
+fn parse_ratio(s: &str) -> Option<f64> {
+    match s.split_once('/') {
+        Some((numerator, denominator)) => {
+            let denominator = denominator.parse::<f64>().ok()?;
+            (denominator != 0.0).then_some(numerator.parse::<f64>().ok()? / denominator)
+        }
+        None => s.parse().ok(),
+    }
+}
+
+/// Ledger entities exposing named fields to filter and sort on.
+pub trait LedgerFields {
+    /// The fields that can be used in predicates, in sort order.
+    const FIELDS: &'static [&'static str];
+
+    /// The text a substring filter matches against, same as the list label.
+    fn label(&self) -> String;
+
+    fn field(&self, name: &str) -> Option<FieldValue>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Op {
+    fn holds(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Eq => ordering == Ordering::Equal,
+            Self::Ne => ordering != Ordering::Equal,
+            Self::Lt => ordering == Ordering::Less,
+            Self::Le => ordering != Ordering::Greater,
+            Self::Gt => ordering == Ordering::Greater,
+            Self::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Term {
+    /// Case insensitive substring of the label.
+    Contains(String),
+    Compare {
+        field: String,
+        op: Op,
+        value: String,
+    },
+}
+
+impl FromStr for Term {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(start) = s.find(['=', '!', '<', '>']) else {
+            return Ok(Self::Contains(s.to_lowercase()));
+        };
+        let (field, rest) = s.split_at(start);
+        let (op, value) = if let Some(value) = rest.strip_prefix(">=") {
+            (Op::Ge, value)
+        } else if let Some(value) = rest.strip_prefix("<=") {
+            (Op::Le, value)
+        } else if let Some(value) = rest.strip_prefix("!=") {
+            (Op::Ne, value)
+        } else if let Some(value) = rest.strip_prefix('>') {
+            (Op::Gt, value)
+        } else if let Some(value) = rest.strip_prefix('<') {
+            (Op::Lt, value)
+        } else if let Some(value) = rest.strip_prefix('=') {
+            (Op::Eq, value)
+        } else {
+            bail!("Invalid operator in {}", s);
+        };
+        if field.is_empty() || value.is_empty() {
+            bail!("Invalid predicate {}, expected <field><op><value>", s);
+        }
+        Ok(Self::Compare {
+            field: field.to_lowercase(),
+            op,
+            value: value.to_string(),
+        })
+    }
+}
+
+/// Whitespace separated terms which must all hold: a substring of the label or
+/// a typed predicate such as `deposit>1000000` or `retiring=true`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filter {
+    pub terms: Vec<Term>,
+}
+
+impl FromStr for Filter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let terms = s
+            .split_whitespace()
+            .map(Term::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { terms })
+    }
+}
+
+impl Filter {
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Fails on predicates naming a field the entity doesn't have.
+    pub fn check_fields<T: LedgerFields>(&self) -> anyhow::Result<()> {
+        for term in &self.terms {
+            if let Term::Compare { field, .. } = term
+                && !T::FIELDS.contains(&field.as_str())
+            {
+                return Err(anyhow!(
+                    "Unknown field {}, expected one of {}",
+                    field,
+                    T::FIELDS.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn matches<T: LedgerFields>(&self, item: &T) -> bool {
+        self.terms.iter().all(|term| match term {
+            Term::Contains(text) => item.label().to_lowercase().contains(text),
+            Term::Compare { field, op, value } => item
+                .field(field)
+                .and_then(|field| field.compare_to(value))
+                .is_some_and(|ordering| op.holds(ordering)),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: &'static str,
+    pub descending: bool,
+}
+
+impl SortKey {
+    /// Orders two items by the field, items missing it last.
+    pub fn compare<T: LedgerFields>(&self, a: &T, b: &T) -> Ordering {
+        match (a.field(self.field), b.field(self.field)) {
+            (Some(a), Some(b)) if self.descending => b.sort_cmp(&a),
+            (Some(a), Some(b)) => a.sort_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// The first `limit` items in this order, holding at most twice as many
+    /// in memory while scanning. Gives up, returning nothing, as soon as
+    /// `cancelled` returns true.
+    pub fn top<T, I>(&self, items: I, limit: usize, cancelled: impl Fn() -> bool) -> Vec<T>
+    where
+        T: LedgerFields,
+        I: IntoIterator<Item = T>,
+    {
+        if limit == 0 {
+            return Vec::new();
+        }
+        let mut top = Vec::new();
+        for item in items {
+            if cancelled() {
+                return Vec::new();
+            }
+            top.push(item);
+            if top.len() == limit * 2 {
+                top.select_nth_unstable_by(limit - 1, |a, b| self.compare(a, b));
+                top.truncate(limit);
+            }
+        }
+        top.sort_by(|a, b| self.compare(a, b));
+        top.truncate(limit);
+        top
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arrow = if self.descending { "↓" } else { "↑" };
+        write!(f, "{} {}", self.field, arrow)
+    }
+}
+
+impl LedgerFields for AccountItem {
+    const FIELDS: &'static [&'static str] = &["deposit", "rewards", "pool", "drep", "delegated"];
+
+    fn label(&self) -> String {
+        StakeCredentialDisplay(&self.0).to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        let row = &self.1;
+        match name {
+            "deposit" => Some(FieldValue::Int(row.deposit)),
+            "rewards" => Some(FieldValue::Int(row.rewards)),
+            "pool" => row
+                .pool
+                .map(|(pool_id, _)| FieldValue::Text(pool_id.to_string())),
+            "drep" => row.drep.as_ref().map(|(drep, _)| drep_field(drep)),
+            "delegated" => Some(FieldValue::Bool(row.pool.is_some())),
+            _ => None,
+        }
+    }
+}
+
+impl LedgerFields for BlockIssuerItem {
+    const FIELDS: &'static [&'static str] = &["slot", "pool"];
+
+    fn label(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "slot" => Some(FieldValue::Int(u64::from(self.0))),
+            "pool" => Some(FieldValue::Text(self.1.slot_leader.to_string())),
+            _ => None,
+        }
+    }
+}
+
+impl LedgerFields for CommitteeMemberItem {
+    const FIELDS: &'static [&'static str] = &["expires_at", "authorized", "hot"];
+
+    fn label(&self) -> String {
+        StakeCredentialDisplay(&self.0).to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        let row = &self.1;
+        match name {
+            "expires_at" => row
+                .expires_at
+                .map(|epoch| FieldValue::Int(u64::from(epoch))),
+            "authorized" => Some(FieldValue::Bool(row.hot_credential.is_some())),
+            "hot" => row
+                .hot_credential
+                .as_ref()
+                .map(|hot| FieldValue::Text(StakeCredentialDisplay(hot).to_string())),
+            _ => None,
+        }
+    }
+}
+
+impl LedgerFields for DRepItem {
+    const FIELDS: &'static [&'static str] = &["deposit", "valid_until", "anchor"];
+
+    fn label(&self) -> String {
+        StakeCredentialDisplay(&self.0).to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        let row = &self.1;
+        match name {
+            "deposit" => Some(FieldValue::Int(row.deposit)),
+            "valid_until" => Some(FieldValue::Int(u64::from(row.valid_until))),
+            "anchor" => row
+                .anchor
+                .as_ref()
+                .map(|anchor| FieldValue::Text(anchor.url.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl LedgerFields for PoolItem {
+    const FIELDS: &'static [&'static str] =
+        &["pledge", "cost", "margin", "retiring", "owners", "relays"];
+
+    fn label(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        let row = &self.1;
+        let params = &row.current_params;
+        match name {
+            "pledge" => Some(FieldValue::Int(params.pledge)),
+            "cost" => Some(FieldValue::Int(params.cost)),
+            "margin" => (params.margin.denominator != 0).then(|| {
+                FieldValue::Ratio(params.margin.numerator as f64 / params.margin.denominator as f64)
+            }),
+            "retiring" => Some(FieldValue::Bool(
+                row.future_params.iter().any(|(params, _)| params.is_none()),
+            )),
+            "owners" => Some(FieldValue::Int(params.owners.len() as u64)),
+            "relays" => Some(FieldValue::Int(params.relays.len() as u64)),
+            _ => None,
+        }
+    }
+}
+
+impl LedgerFields for ProposalItem {
+    const FIELDS: &'static [&'static str] = &["deposit", "valid_until", "type"];
+
+    fn label(&self) -> String {
+        ComparableProposalIdDisplay(&self.0).to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        let row = &self.1;
+        match name {
+            "deposit" => Some(FieldValue::Int(row.proposal.deposit)),
+            "valid_until" => Some(FieldValue::Int(u64::from(row.valid_until))),
+            "type" => Some(FieldValue::Text(
+                gov_action_type(&row.proposal.gov_action).to_lowercase(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl LedgerFields for ProposalEntry {
+    const FIELDS: &'static [&'static str] = &["deposit", "valid_until", "type", "epoch", "status"];
+
+    fn label(&self) -> String {
+        self.item.label()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "epoch" => self.epoch.map(|epoch| FieldValue::Int(u64::from(epoch))),
+            "status" => self
+                .status
+                .map(|status| FieldValue::Text(status.to_string())),
+            _ => self.item.field(name),
+        }
+    }
+}
+
+impl LedgerFields for UtxoItem {
+    const FIELDS: &'static [&'static str] = &["lovelace", "assets", "datum", "script", "address"];
+
+    fn label(&self) -> String {
+        TransactionInputDisplay(&self.0).to_string()
+    }
+
+    fn field(&self, name: &str) -> Option<FieldValue> {
+        let output = &self.1;
+        match name {
+            "lovelace" => Some(FieldValue::Int(match &output.value {
+                Value::Coin(coin) | Value::Multiasset(coin, _) => *coin,
+            })),
+            "assets" => Some(FieldValue::Int(match &output.value {
+                Value::Coin(_) => 0,
+                Value::Multiasset(_, assets) => {
+                    assets.iter().map(|(_, aset)| aset.len() as u64).sum()
+                }
+            })),
+            "datum" => Some(FieldValue::Bool(!matches!(
+                output.datum,
+                MemoizedDatum::None
+            ))),
+            "script" => Some(FieldValue::Bool(output.script.is_some())),
+            "address" => output.address.to_bech32().ok().map(FieldValue::Text),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amaru_kernel::Hash;
+
+    struct Item {
+        label: &'static str,
+        deposit: u64,
+        retiring: bool,
+        drep: DRep,
+    }
+
+    impl LedgerFields for Item {
+        const FIELDS: &'static [&'static str] = &["deposit", "retiring", "drep"];
+
+        fn label(&self) -> String {
+            self.label.to_string()
+        }
+
+        fn field(&self, name: &str) -> Option<FieldValue> {
+            match name {
+                "deposit" => Some(FieldValue::Int(self.deposit)),
+                "retiring" => Some(FieldValue::Bool(self.retiring)),
+                "drep" => Some(drep_field(&self.drep)),
+                _ => None,
+            }
+        }
+    }
+
+    const ITEM: Item = Item {
+        label: "pool1abc",
+        deposit: 2_000_000,
+        retiring: true,
+        drep: DRep::Abstain,
+    };
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = Filter::from_str("ABC deposit>=1_000 retiring!=false").unwrap();
+        assert_eq!(
+            filter.terms,
+            vec![
+                Term::Contains("abc".to_string()),
+                Term::Compare {
+                    field: "deposit".to_string(),
+                    op: Op::Ge,
+                    value: "1_000".to_string(),
+                },
+                Term::Compare {
+                    field: "retiring".to_string(),
+                    op: Op::Ne,
+                    value: "false".to_string(),
+                },
+            ]
+        );
+        assert!(Filter::from_str("deposit>").is_err());
+        assert!(Filter::from_str("=1").is_err());
+        assert!(Filter::from_str("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let matches = |s: &str| Filter::from_str(s).unwrap().matches(&ITEM);
+        assert!(matches("abc"));
+        assert!(matches("deposit>1000000 retiring=true"));
+        assert!(matches("deposit=2_000_000"));
+        assert!(!matches("deposit<1000000"));
+        assert!(!matches("abc retiring=false"));
+        assert!(!matches("deposit>lots"));
+        assert!(!matches("xyz"));
+        assert!(matches("drep=Abstain"));
+        assert!(!matches("drep=NoConfidence"));
+    }
+
+    #[test]
+    fn test_drep_filter_matches_cell() {
+        let item = Item {
+            drep: DRep::Key(Hash::new([0xab; 28])),
+            ..ITEM
+        };
+        let filter = format!("drep=Key({})", "ab".repeat(28));
+        assert!(Filter::from_str(&filter).unwrap().matches(&item));
+        assert!(!Filter::from_str("drep=Abstain").unwrap().matches(&item));
+    }
+
+    #[test]
+    fn test_check_fields() {
+        assert!(
+            Filter::from_str("deposit>1")
+                .unwrap()
+                .check_fields::<Item>()
+                .is_ok()
+        );
+        assert!(
+            Filter::from_str("stake>1")
+                .unwrap()
+                .check_fields::<Item>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_sort_top() {
+        let items = |deposits: &[u64]| -> Vec<Item> {
+            deposits
+                .iter()
+                .map(|&deposit| Item { deposit, ..ITEM })
+                .collect()
+        };
+        let deposits = |items: Vec<Item>| -> Vec<u64> { items.iter().map(|i| i.deposit).collect() };
+        let ascending = SortKey {
+            field: "deposit",
+            descending: false,
+        };
+        let descending = SortKey {
+            descending: true,
+            ..ascending
+        };
+        let unsorted = [5, 3, 9, 1, 7, 2, 8, 6, 4];
+
+        assert_eq!(
+            deposits(ascending.top(items(&unsorted), 3, || false)),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            deposits(descending.top(items(&unsorted), 4, || false)),
+            vec![9, 8, 7, 6]
+        );
+        assert_eq!(
+            deposits(ascending.top(items(&unsorted), 20, || false)),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+        );
+        assert!(ascending.top(items(&unsorted), 0, || false).is_empty());
+        assert!(ascending.top(items(&unsorted), 3, || true).is_empty());
+    }
+
+    #[test]
+    fn test_parse_ratio() {
+        assert_eq!(parse_ratio("1/20"), Some(0.05));
+        assert_eq!(parse_ratio("0.05"), Some(0.05));
+        assert_eq!(parse_ratio("1/0"), None);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::model::{
+    async_provider::AsyncProvider,
+    ledger_diff::{ColumnItem, DiffKind, LedgerColumn, LedgerDifference, diff_ledgers},
+};
+use amaru_stores::rocksdb::{ReadOnlyRocksDB, RocksDbConfig};
+use chrono::{DateTime, Local};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc::error::TryRecvError;
+use tracing::{info, warn};
+
+/// Differences recorded per column. A refresh after a long sync could touch
+/// most of the UTXO set, the rest is only counted.
+const MAX_CHANGES_PER_COLUMN: usize = 10_000;
+
+/// How the entries of a list changed since the previous refresh.
+pub struct RowChanges<T> {
+    /// Removed, added and changed entries, by identifier.
+    pub kinds: HashMap<String, DiffKind>,
+    /// The last value of the removed entries.
+    pub removed: Vec<T>,
+}
+
+impl<T> Default for RowChanges<T> {
+    fn default() -> Self {
+        Self {
+            kinds: HashMap::new(),
+            removed: Vec::new(),
+        }
+    }
+}
+
+/// The differences between the ledger before and after a refresh.
+#[derive(Default)]
+pub struct LedgerChanges {
+    columns: HashMap<LedgerColumn, Vec<LedgerDifference>>,
+    /// Differences left out once a column reached `MAX_CHANGES_PER_COLUMN`.
+    pub skipped: usize,
+}
+
+impl LedgerChanges {
+    fn record(&mut self, difference: LedgerDifference) {
+        let column = self.columns.entry(difference.column).or_default();
+        if column.len() < MAX_CHANGES_PER_COLUMN {
+            column.push(difference);
+        } else {
+            self.skipped += 1;
+        }
+    }
+
+    /// The number of removed, added and changed entries recorded.
+    pub fn counts(&self) -> (usize, usize, usize) {
+        let mut counts = (0, 0, 0);
+        for difference in self.columns.values().flatten() {
+            match difference.kind() {
+                DiffKind::Removed => counts.0 += 1,
+                DiffKind::Added => counts.1 += 1,
+                DiffKind::Changed => counts.2 += 1,
+            }
+        }
+        counts
+    }
+
+    /// Takes the changes of the column holding `T` items.
+    pub fn take_rows<T: ColumnItem>(&mut self) -> RowChanges<T> {
+        let mut rows = RowChanges::default();
+        for difference in self.columns.remove(&T::COLUMN).unwrap_or_default() {
+            rows.kinds
+                .insert(difference.identifier(), difference.kind());
+            if difference.right.is_none()
+                && let Some(item) = difference.left.and_then(T::from_entry)
+            {
+                rows.removed.push(item);
+            }
+        }
+        rows
+    }
+}
+
+/// The ledger db re-opened, with what changed since it was last opened.
+pub struct Refreshed {
+    pub db: Arc<ReadOnlyRocksDB>,
+    pub changes: LedgerChanges,
+}
+
+/// Re-opens the ledger db, periodically or on demand, so the doctor catches
+/// up with the node writing it. A read-only db only sees the data written
+/// before it was opened.
+pub struct LedgerRefresh {
+    path: PathBuf,
+    interval: Option<Duration>,
+    last_started: Instant,
+    last_refreshed: Option<(DateTime<Local>, (usize, usize, usize))>,
+    /// The columns diffed on refresh, those whose list was shown. The others
+    /// are reloaded without highlights.
+    watched: Vec<LedgerColumn>,
+    job: Option<AsyncProvider<Refreshed>>,
+}
+
+impl LedgerRefresh {
+    pub fn new(path: PathBuf, interval: Option<Duration>) -> Self {
+        Self {
+            path,
+            interval,
+            last_started: Instant::now(),
+            last_refreshed: None,
+            watched: Vec::new(),
+            job: None,
+        }
+    }
+
+    pub fn is_refreshing(&self) -> bool {
+        self.job.is_some()
+    }
+
+    /// Diffs `column` on the next refreshes.
+    pub fn watch(&mut self, column: LedgerColumn) {
+        if !self.watched.contains(&column) {
+            self.watched.push(column);
+        }
+    }
+
+    /// Starts re-opening the db in the background, and diffing it against
+    /// `current`, unless a refresh is running already.
+    pub fn start(&mut self, current: Arc<ReadOnlyRocksDB>) {
+        if self.job.is_some() {
+            return;
+        }
+        self.last_started = Instant::now();
+        let path = self.path.clone();
+        let columns = self.watched.clone();
+        self.job = Some(AsyncProvider::new(move |tx| {
+            let result = ReadOnlyRocksDB::new(RocksDbConfig::new(path))
+                .map_err(anyhow::Error::from)
+                .and_then(|db| {
+                    let mut changes = LedgerChanges::default();
+                    diff_ledgers(&current, &db, &columns, |difference| {
+                        changes.record(difference);
+                        !tx.is_closed()
+                    })?;
+                    Ok(Refreshed {
+                        db: Arc::new(db),
+                        changes,
+                    })
+                });
+            match result {
+                Ok(refreshed) => {
+                    let _ = tx.blocking_send(refreshed);
+                }
+                Err(e) => warn!("Failed to refresh the ledger db: {}", e),
+            }
+        }));
+    }
+
+    /// Starts a refresh once the interval elapsed, and returns the refreshed
+    /// db when one is ready.
+    pub fn poll(&mut self, current: &Arc<ReadOnlyRocksDB>) -> Option<Refreshed> {
+        if let Some(interval) = self.interval
+            && self.last_started.elapsed() >= interval
+        {
+            self.start(current.clone());
+        }
+
+        let job = self.job.as_mut()?;
+        match job.rx.try_recv() {
+            Ok(refreshed) => {
+                self.job = None;
+                let counts = refreshed.changes.counts();
+                info!(
+                    "Ledger db refreshed: {} removed, {} added, {} changed, {} more not highlighted",
+                    counts.0, counts.1, counts.2, refreshed.changes.skipped
+                );
+                self.last_refreshed = Some((Local::now(), counts));
+                Some(refreshed)
+            }
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                self.job = None;
+                None
+            }
+        }
+    }
+
+    /// A one line summary of the last refresh.
+    pub fn status(&self) -> String {
+        let last = match self.last_refreshed {
+            Some((at, (removed, added, changed))) => format!(
+                "refreshed {} · -{} +{} ~{}",
+                at.format("%H:%M:%S"),
+                removed,
+                added,
+                changed
+            ),
+            None => "not refreshed".to_string(),
+        };
+        if self.is_refreshing() {
+            format!("refreshing... ({})", last)
+        } else {
+            last
+        }
+    }
+}
@@ -3,23 +3,130 @@ use crate::{
     model::async_provider::AsyncProvider,
     ui::to_list_item::UtxoItem,
 };
-use amaru_kernel::Address;
+use amaru_kernel::{Address, Bytes, Hash, MemoizedTransactionOutput, TransactionInput, Value};
 use amaru_ledger::store::ReadStore;
 use amaru_stores::rocksdb::ReadOnlyRocksDB;
-use std::sync::Arc;
+use anyhow::anyhow;
+use std::{str::FromStr, sync::Arc};
+
+/// A transaction input parsed from `txid#index` (`txid:index` is accepted too,
+/// matching how inputs are displayed).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UtxoInput(pub TransactionInput);
+
+impl FromStr for UtxoInput {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, index) = s
+            .trim()
+            .split_once(['#', ':'])
+            .ok_or_else(|| anyhow!("Invalid input {}, expected <txid>#<index>", s))?;
+        let transaction_id =
+            Hash::<32>::from_str(id).map_err(|e| anyhow!("Invalid tx id {}: {:?}", id, e))?;
+        let index = index
+            .parse()
+            .map_err(|e| anyhow!("Invalid index {}: {}", index, e))?;
+        Ok(Self(TransactionInput {
+            transaction_id,
+            index,
+        }))
+    }
+}
 
 pub struct LedgerUtxoProvider {
     pub db: Arc<ReadOnlyRocksDB>,
 }
 
-impl SearchProvider<Address, UtxoItem> for LedgerUtxoProvider {
-    fn search(&self, address: &Address) -> Option<AsyncListModel<UtxoItem>> {
+/// The hash of a stake credential, either key or script, given as hex or as a
+/// bech32 stake address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StakeCredentialQuery(pub Hash<28>);
+
+impl FromStr for StakeCredentialQuery {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(Address::Stake(stake)) = Address::from_str(s) {
+            return Ok(Self(*stake.payload().as_hash()));
+        }
+        parse_credential_hash(s).map(Self)
+    }
+}
+
+/// The hash of a payment credential, either key or script, given as hex.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentCredentialQuery(pub Hash<28>);
+
+impl FromStr for PaymentCredentialQuery {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_credential_hash(s.trim()).map(Self)
+    }
+}
+
+fn parse_credential_hash(s: &str) -> anyhow::Result<Hash<28>> {
+    Hash::<28>::from_str(s).map_err(|e| anyhow!("Invalid credential {}: {:?}", s, e))
+}
+
+/// A native asset policy id, optionally followed by `.` and a hex-encoded
+/// asset name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssetQuery {
+    pub policy: Hash<28>,
+    pub name: Option<Bytes>,
+}
+
+impl FromStr for AssetQuery {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (policy, name) = match s.split_once('.') {
+            Some((policy, name)) => (policy, Some(name)),
+            None => (s, None),
+        };
+        let policy = Hash::<28>::from_str(policy)
+            .map_err(|e| anyhow!("Invalid policy id {}: {:?}", policy, e))?;
+        let name = name
+            .map(|name| {
+                hex::decode(name)
+                    .map(Bytes::from)
+                    .map_err(|e| anyhow!("Invalid asset name {}: {}", name, e))
+            })
+            .transpose()?;
+        Ok(Self { policy, name })
+    }
+}
+
+impl AssetQuery {
+    fn matches(&self, value: &Value) -> bool {
+        let Value::Multiasset(_, assets) = value else {
+            return false;
+        };
+        assets.iter().any(|(policy, aset)| {
+            *policy == self.policy
+                && self
+                    .name
+                    .as_ref()
+                    .is_none_or(|name| aset.iter().any(|(asset_name, _)| asset_name == name))
+        })
+    }
+}
+
+impl LedgerUtxoProvider {
+    /// Streams every UTXO whose output matches the predicate.
+    fn search_filtered<F>(&self, title: &'static str, pred: F) -> AsyncListModel<UtxoItem>
+    where
+        F: Fn(&MemoizedTransactionOutput) -> bool + Send + 'static,
+    {
         let db = self.db.clone();
-        let owned_addr = address.clone();
 
         let provider = AsyncProvider::new(move |tx| {
             if let Ok(iter) = ReadStore::iter_utxos(&*db) {
-                let filtered = iter.filter(move |(_, out)| out.address == owned_addr);
+                let filtered = iter.filter(move |(_, out)| pred(out));
                 for item in filtered {
                     if tx.blocking_send(item).is_err() {
                         break;
@@ -27,6 +134,116 @@ impl SearchProvider<Address, UtxoItem> for LedgerUtxoProvider {
                 }
             }
         });
-        Some(AsyncListModel::new("Utxos by Addr", provider))
+        AsyncListModel::new(title, provider)
+    }
+}
+
+fn payment_hash(address: &Address) -> Option<&Hash<28>> {
+    match address {
+        Address::Shelley(shelley) => Some(shelley.payment().as_hash()),
+        _ => None,
+    }
+}
+
+/// The stake credential hash of an address, for the addresses that have one.
+pub fn stake_hash(address: &Address) -> Option<&Hash<28>> {
+    match address {
+        Address::Shelley(shelley) => shelley.delegation().as_hash(),
+        Address::Stake(stake) => Some(stake.payload().as_hash()),
+        _ => None,
+    }
+}
+
+impl SearchProvider<Address, UtxoItem> for LedgerUtxoProvider {
+    fn search(&self, address: &Address) -> Option<AsyncListModel<UtxoItem>> {
+        let owned_addr = address.clone();
+        Some(self.search_filtered("Utxos by Addr", move |out| out.address == owned_addr))
+    }
+}
+
+impl SearchProvider<StakeCredentialQuery, UtxoItem> for LedgerUtxoProvider {
+    fn search(&self, query: &StakeCredentialQuery) -> Option<AsyncListModel<UtxoItem>> {
+        let hash = query.0;
+        Some(
+            self.search_filtered("Utxos by Stake Credential", move |out| {
+                stake_hash(&out.address) == Some(&hash)
+            }),
+        )
+    }
+}
+
+impl SearchProvider<PaymentCredentialQuery, UtxoItem> for LedgerUtxoProvider {
+    fn search(&self, query: &PaymentCredentialQuery) -> Option<AsyncListModel<UtxoItem>> {
+        let hash = query.0;
+        Some(
+            self.search_filtered("Utxos by Payment Credential", move |out| {
+                payment_hash(&out.address) == Some(&hash)
+            }),
+        )
+    }
+}
+
+impl SearchProvider<AssetQuery, UtxoItem> for LedgerUtxoProvider {
+    fn search(&self, query: &AssetQuery) -> Option<AsyncListModel<UtxoItem>> {
+        let query = query.clone();
+        Some(self.search_filtered("Utxos by Asset", move |out| query.matches(&out.value)))
+    }
+}
+
+impl SearchProvider<UtxoInput, UtxoItem> for LedgerUtxoProvider {
+    fn search(&self, input: &UtxoInput) -> Option<AsyncListModel<UtxoItem>> {
+        let db = self.db.clone();
+        let input = input.0.clone();
+
+        let provider = AsyncProvider::new(move |tx| {
+            if let Ok(Some(output)) = ReadStore::utxo(&*db, &input) {
+                let _ = tx.blocking_send((input, output));
+            }
+        });
+        Some(AsyncListModel::new("Utxo by Input", provider))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TX_ID: &str = "2e6b2226fd74ab0cadc53aaa18759752752bd9b616ea48c0e7b7be77d1af4bf4";
+
+    #[test]
+    fn test_parse_utxo_input() {
+        let expected = TransactionInput {
+            transaction_id: Hash::<32>::from_str(TX_ID).unwrap(),
+            index: 3,
+        };
+        assert_eq!(
+            UtxoInput::from_str(&format!("{TX_ID}#3")).unwrap().0,
+            expected
+        );
+        assert_eq!(
+            UtxoInput::from_str(&format!("{TX_ID}:3")).unwrap().0,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_asset_query() {
+        let policy = "1d7f33bd23d85e1a25d87d86fac4f199c3197a2f7afeb662a0f34e1e";
+        let query = AssetQuery::from_str(policy).unwrap();
+        assert_eq!(query.policy, Hash::<28>::from_str(policy).unwrap());
+        assert_eq!(query.name, None);
+
+        let query =
+            AssetQuery::from_str(&format!("{policy}.776f726c646d6f62696c65746f6b656e")).unwrap();
+        assert_eq!(query.name, Some(Bytes::from(b"worldmobiletoken".to_vec())));
+
+        assert!(AssetQuery::from_str(&format!("{policy}.zz")).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_utxo_input() {
+        assert!(UtxoInput::from_str(TX_ID).is_err());
+        assert!(UtxoInput::from_str(&format!("{TX_ID}#x")).is_err());
+        assert!(UtxoInput::from_str("abc#1").is_err());
     }
 }
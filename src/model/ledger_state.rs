@@ -0,0 +1,195 @@
+use amaru_kernel::{
+    network::NetworkName,
+    protocol_parameters::{
+        PREPROD_INITIAL_PROTOCOL_PARAMETERS, PREVIEW_INITIAL_PROTOCOL_PARAMETERS,
+        ProtocolParameters,
+    },
+};
+use amaru_ledger::store::{ReadStore, Snapshot};
+use amaru_slot_arithmetic::Epoch;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use std::fmt::Debug;
+
+/// A named value of the ledger state, along with its initial value when it
+/// differs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LedgerStateField {
+    pub name: String,
+    pub value: Vec<String>,
+    pub initial: Option<Vec<String>>,
+}
+
+impl LedgerStateField {
+    fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: vec![value.into()],
+            initial: None,
+        }
+    }
+
+    /// A field along with its initial value, kept only when they differ.
+    fn compared<T: Debug + PartialEq>(name: &str, value: &T, initial: Option<&T>) -> Self {
+        Self {
+            name: name.to_string(),
+            value: debug_lines(value),
+            initial: initial.filter(|initial| *initial != value).map(debug_lines),
+        }
+    }
+
+    pub fn changed(&self) -> bool {
+        self.initial.is_some()
+    }
+}
+
+/// The lines of the pretty `Debug` output of a value.
+fn debug_lines<T: Debug>(value: &T) -> Vec<String> {
+    format!("{:#?}", value).lines().map(String::from).collect()
+}
+
+/// Lists the given fields of `$current`, each compared to the same field of
+/// `$initial`, an `Option` of the same type.
+macro_rules! compared_fields {
+    ($current:expr, $initial:expr; $($field:ident),* $(,)?) => {
+        vec![$(LedgerStateField::compared(
+            stringify!($field),
+            &$current.$field,
+            $initial.map(|initial| &initial.$field),
+        )),*]
+    };
+}
+
+/// The protocol parameters a network started with, when known.
+fn initial_protocol_parameters(network: &NetworkName) -> Option<&'static ProtocolParameters> {
+    match network {
+        NetworkName::Preprod => Some(&*PREPROD_INITIAL_PROTOCOL_PARAMETERS),
+        NetworkName::Preview => Some(&*PREVIEW_INITIAL_PROTOCOL_PARAMETERS),
+        _ => None,
+    }
+}
+
+/// The protocol parameters in use, each compared to the initial parameters of
+/// the network.
+pub fn load_protocol_parameters(
+    db: &ReadOnlyRocksDB,
+    network: &NetworkName,
+) -> anyhow::Result<Vec<LedgerStateField>> {
+    let current = ReadStore::protocol_parameters(db)?;
+    let initial = initial_protocol_parameters(network);
+    Ok(compared_fields!(current, initial;
+        protocol_version,
+        max_block_body_size,
+        max_transaction_size,
+        max_block_header_size,
+        max_tx_ex_units,
+        max_block_ex_units,
+        max_value_size,
+        max_collateral_inputs,
+        min_fee_a,
+        min_fee_b,
+        stake_credential_deposit,
+        stake_pool_deposit,
+        monetary_expansion_rate,
+        treasury_expansion_rate,
+        coins_per_utxo_byte,
+        prices,
+        min_fee_ref_script_coins_per_byte,
+        max_ref_script_size_per_tx,
+        max_ref_script_size_per_block,
+        ref_script_cost_stride,
+        ref_script_cost_multiplier,
+        stake_pool_max_retirement_epoch,
+        optimal_stake_pools_count,
+        pledge_influence,
+        collateral_percentage,
+        cost_models,
+        pool_voting_thresholds,
+        drep_voting_thresholds,
+        min_committee_size,
+        max_committee_term_length,
+        gov_action_lifetime,
+        gov_action_deposit,
+        drep_deposit,
+        drep_expiry,
+    ))
+}
+
+/// The epoch of the ledger snapshot and its pots, in lovelace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EpochState {
+    pub epoch: Epoch,
+    pub treasury: u64,
+    pub reserves: u64,
+    pub fees: u64,
+}
+
+impl EpochState {
+    pub fn fields(&self) -> Vec<LedgerStateField> {
+        vec![
+            LedgerStateField::new("epoch", self.epoch.to_string()),
+            LedgerStateField::new("treasury", self.treasury.to_string()),
+            LedgerStateField::new("reserves", self.reserves.to_string()),
+            LedgerStateField::new("fees", self.fees.to_string()),
+        ]
+    }
+}
+
+pub fn load_epoch_state(db: &ReadOnlyRocksDB) -> anyhow::Result<EpochState> {
+    let pots = ReadStore::pots(db)?;
+    Ok(EpochState {
+        epoch: Snapshot::epoch(db),
+        treasury: pots.treasury,
+        reserves: pots.reserves,
+        fees: pots.fees,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Params {
+        fee: u64,
+        ratio: (u64, u64),
+        costs: Option<Vec<i64>>,
+    }
+
+    #[test]
+    fn test_compared_fields() {
+        let current = Params {
+            fee: 44,
+            ratio: (1, 2),
+            costs: Some(vec![1, -2]),
+        };
+        let initial = Params {
+            fee: 44,
+            ratio: (1, 3),
+            costs: None,
+        };
+
+        let fields = compared_fields!(current, Some(&initial); fee, ratio, costs);
+        assert_eq!(
+            fields
+                .iter()
+                .map(|field| (field.name.as_str(), field.changed()))
+                .collect::<Vec<_>>(),
+            vec![("fee", false), ("ratio", true), ("costs", true)]
+        );
+        assert_eq!(fields[0].value, vec!["44"]);
+        assert_eq!(fields[1].value, vec!["(", "    1,", "    2,", ")"]);
+        assert_eq!(
+            fields[1].initial,
+            Some(
+                vec!["(", "    1,", "    3,", ")"]
+                    .into_iter()
+                    .map(String::from)
+                    .collect()
+            )
+        );
+        assert_eq!(fields[2].initial, Some(vec!["None".to_string()]));
+
+        let fields = compared_fields!(current, None::<&Params>; fee, costs);
+        assert!(fields.iter().all(|field| !field.changed()));
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
-    model::{buffer_list::BufferList, streaming_iter::StreamingIter},
-    ui::to_list_item::ToListItem,
+    model::{buffer_list::BufferList, streaming_iter::StreamingIter, table_columns::TableColumns},
+    ui::{to_list_item::ToListItem, to_table_row::ToTableRow},
     view::list::ListViewState,
 };
-use ratatui::{prelude::Frame, prelude::Rect};
+use ratatui::{prelude::Frame, prelude::Rect, style::Style};
 use tracing::debug;
 
 /// A stateful component that encapsulates both the data model (`StreamingIter`)
@@ -41,6 +41,38 @@ impl<T: ToListItem> ListModelView<T> {
         self.iter.buffer().get(index)
     }
 
+    /// Selects the first item matching the predicate, streaming more items in
+    /// as needed. Returns false, leaving the selection as is, when no item
+    /// matches.
+    pub fn select_first<F>(&mut self, pred: F) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.select_first_within(pred, usize::MAX)
+    }
+
+    /// Same as `select_first`, looking at the first `limit` items only.
+    pub fn select_first_within<F>(&mut self, pred: F, limit: usize) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut index = 0;
+        while index < limit {
+            self.iter.load_up_to(index);
+            match self.iter.buffer().get(index) {
+                Some(item) if pred(item) => {
+                    self.view.select(index, self.iter.buffer().len());
+                    let required_index = self.view.max_visible_index();
+                    self.iter.load_up_to(required_index);
+                    return true;
+                }
+                Some(_) => index += 1,
+                None => return false,
+            }
+        }
+        false
+    }
+
     /// Sets the selected index based on a row clicked within the visible
     /// window.
     pub fn select_index_by_row(&mut self, relative_row: usize) {
@@ -96,4 +128,39 @@ impl<T: ToListItem> ListModelView<T> {
     pub fn draw(&self, f: &mut Frame, area: Rect, is_focsued: bool) {
         self.view.draw(f, area, &self.iter, is_focsued);
     }
+
+    /// Draws the list component, with the style `row_style` returns for
+    /// some of the items.
+    pub fn draw_styled(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        is_focused: bool,
+        row_style: impl Fn(&T) -> Option<Style>,
+    ) {
+        self.view
+            .draw_styled(f, area, &self.iter, is_focused, row_style);
+    }
+}
+
+impl<T: ToTableRow> ListModelView<T> {
+    /// Draws the list as a table.
+    pub fn draw_table(&self, f: &mut Frame, area: Rect, columns: &TableColumns, is_focused: bool) {
+        self.view
+            .draw_table(f, area, &self.iter, columns, is_focused);
+    }
+
+    /// Draws the list as a table, with the style `row_style` returns for some
+    /// of the rows.
+    pub fn draw_table_styled(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        columns: &TableColumns,
+        is_focused: bool,
+        row_style: impl Fn(&T) -> Option<Style>,
+    ) {
+        self.view
+            .draw_table_styled(f, area, &self.iter, columns, is_focused, row_style);
+    }
 }
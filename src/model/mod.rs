@@ -1,12 +1,26 @@
+pub mod account_details;
 pub mod async_provider;
+pub mod block;
+pub mod block_production;
 pub mod buffer_list;
 pub mod button;
+pub mod chain_query;
 pub mod cursor;
+pub mod fork_tree;
+pub mod governance;
+pub mod ledger_diff;
+pub mod ledger_filter;
+pub mod ledger_refresh;
 pub mod ledger_search;
+pub mod ledger_state;
 pub mod list_view;
 pub mod min_max_window;
 pub mod otel_view;
+pub mod proposal;
 pub mod search;
+pub mod stake;
 pub mod stats;
 pub mod streaming_iter;
+pub mod table_columns;
 pub mod time_series;
+pub mod utxo_totals;
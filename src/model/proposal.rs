@@ -0,0 +1,96 @@
+use crate::{store::owned_iter::OwnedProposalIter, ui::to_list_item::ProposalItem};
+use amaru_kernel::network::NetworkName;
+use amaru_ledger::store::Snapshot;
+use amaru_slot_arithmetic::{Epoch, EraHistory};
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use std::sync::Arc;
+use strum::Display;
+
+/// Where a proposal stands at the epoch of the ledger snapshot.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum ProposalStatus {
+    /// Still open to votes.
+    Active,
+    /// Past its last epoch, waiting to be removed.
+    Expired,
+}
+
+impl ProposalStatus {
+    pub fn of(valid_until: Epoch, current_epoch: Epoch) -> Self {
+        if current_epoch > valid_until {
+            Self::Expired
+        } else {
+            Self::Active
+        }
+    }
+}
+
+/// A proposal of the proposals list, with the epoch it was submitted in and
+/// its status.
+#[derive(Clone)]
+pub struct ProposalEntry {
+    pub item: ProposalItem,
+    /// `None` when the era history doesn't cover the slot, and for the
+    /// entries removed on refresh.
+    pub epoch: Option<Epoch>,
+    pub status: Option<ProposalStatus>,
+}
+
+impl ProposalEntry {
+    pub fn new(item: ProposalItem, era_history: &EraHistory, current_epoch: Epoch) -> Self {
+        let slot = item.1.proposed_in.transaction.slot;
+        Self {
+            epoch: era_history.slot_to_epoch(slot, slot).ok(),
+            status: Some(ProposalStatus::of(item.1.valid_until, current_epoch)),
+            item,
+        }
+    }
+
+    /// An entry read from another db, whose epoch is not known.
+    pub fn removed(item: ProposalItem) -> Self {
+        Self {
+            item,
+            epoch: None,
+            status: None,
+        }
+    }
+}
+
+/// Exported as the proposal itself, the epoch and status being derived.
+impl minicbor::Encode<()> for ProposalEntry {
+    fn encode<W: minicbor::encode::Write>(
+        &self,
+        e: &mut minicbor::Encoder<W>,
+        ctx: &mut (),
+    ) -> Result<(), minicbor::encode::Error<W::Error>> {
+        minicbor::Encode::encode(&self.item, e, ctx)
+    }
+}
+
+/// The proposals of the ledger, with their epoch and status at the epoch of
+/// the snapshot.
+pub fn proposal_entries(
+    db: Arc<ReadOnlyRocksDB>,
+    network: NetworkName,
+) -> impl Iterator<Item = ProposalEntry> + Send + Sync + 'static {
+    let era_history: &'static EraHistory = network.into();
+    let current_epoch = Snapshot::epoch(&*db);
+    OwnedProposalIter::new(db).map(move |item| ProposalEntry::new(item, era_history, current_epoch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proposal_status() {
+        let status = |valid_until: u64, current: u64| {
+            ProposalStatus::of(Epoch::from(valid_until), Epoch::from(current))
+        };
+        assert_eq!(status(10, 9), ProposalStatus::Active);
+        assert_eq!(status(10, 10), ProposalStatus::Active);
+        assert_eq!(status(10, 11), ProposalStatus::Expired);
+        assert_eq!(ProposalStatus::Expired.to_string(), "expired");
+    }
+}
@@ -0,0 +1,240 @@
+use crate::model::{
+    account_details::drep_credential, async_provider::AsyncProvider, ledger_search::stake_hash,
+};
+use amaru_kernel::{DRep, Hash, PoolId, StakeCredential, Value};
+use amaru_ledger::store::ReadStore;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::mpsc::error::TryRecvError;
+use tracing::warn;
+
+/// The lovelace ever to exist, part of which is still held in the reserves.
+pub const MAX_LOVELACE_SUPPLY: u64 = 45_000_000_000_000_000;
+
+/// The stake above which a pool's rewards stop growing: a `k`-th of the
+/// circulating supply, that is the maximum supply less the reserves.
+pub fn saturation_point(reserves: u64, k: u64) -> u64 {
+    MAX_LOVELACE_SUPPLY.saturating_sub(reserves) / k.max(1)
+}
+
+/// Stake delegated to each pool and DRep, aggregated from the accounts'
+/// delegations. The stake of an account is its rewards plus the lovelace held
+/// by the UTXOs of its stake credential.
+#[derive(Debug, Default)]
+pub struct StakeDistribution {
+    pub pools: HashMap<PoolId, u64>,
+    pub dreps: HashMap<StakeCredential, u64>,
+    pub always_abstain: u64,
+    pub always_no_confidence: u64,
+}
+
+fn credential_hash(credential: &StakeCredential) -> Hash<28> {
+    match credential {
+        StakeCredential::AddrKeyhash(hash) | StakeCredential::ScriptHash(hash) => *hash,
+    }
+}
+
+fn lovelace(value: &Value) -> u64 {
+    match value {
+        Value::Coin(coin) | Value::Multiasset(coin, _) => *coin,
+    }
+}
+
+impl StakeDistribution {
+    /// Scans the UTXO set then the accounts. `on_progress` is called every
+    /// `PROGRESS_STEP` UTXOs with the number scanned so far, and stops the
+    /// scan, returning `None`, when it returns false.
+    pub fn load(
+        db: &ReadOnlyRocksDB,
+        mut on_progress: impl FnMut(usize) -> bool,
+    ) -> anyhow::Result<Option<Self>> {
+        const PROGRESS_STEP: usize = 10_000;
+
+        let mut utxo_stake: HashMap<Hash<28>, u64> = HashMap::new();
+        for (i, (_, output)) in ReadStore::iter_utxos(db)?.enumerate() {
+            if let Some(hash) = stake_hash(&output.address) {
+                *utxo_stake.entry(*hash).or_default() += lovelace(&output.value);
+            }
+            if (i + 1) % PROGRESS_STEP == 0 && !on_progress(i + 1) {
+                return Ok(None);
+            }
+        }
+
+        let mut distribution = Self::default();
+        for (credential, row) in ReadStore::iter_accounts(db)? {
+            let stake = row.rewards
+                + utxo_stake
+                    .get(&credential_hash(&credential))
+                    .copied()
+                    .unwrap_or_default();
+            if let Some((pool_id, _)) = row.pool {
+                *distribution.pools.entry(pool_id).or_default() += stake;
+            }
+            match row.drep.as_ref().map(|(drep, _)| drep) {
+                Some(DRep::Abstain) => distribution.always_abstain += stake,
+                Some(DRep::NoConfidence) => distribution.always_no_confidence += stake,
+                Some(drep) => {
+                    if let Some(credential) = drep_credential(drep) {
+                        *distribution.dreps.entry(credential).or_default() += stake;
+                    }
+                }
+                None => {}
+            }
+        }
+        Ok(Some(distribution))
+    }
+
+    pub fn total_pool_stake(&self) -> u64 {
+        self.pools.values().sum()
+    }
+
+    /// The stake of the registered DReps, not counting the predefined ones.
+    pub fn total_drep_stake(&self) -> u64 {
+        self.dreps.values().sum()
+    }
+
+    /// The pools ranked by stake, largest first, with their stake relative
+    /// to `saturation_point`.
+    pub fn rank_pools(&self, saturation_point: u64) -> Vec<PoolStake> {
+        let total = self.total_pool_stake();
+
+        let mut pools: Vec<_> = self.pools.iter().collect();
+        pools.sort_by(|(a_id, a), (b_id, b)| b.cmp(a).then(a_id.cmp(b_id)));
+        pools
+            .into_iter()
+            .enumerate()
+            .map(|(i, (pool_id, stake))| PoolStake {
+                rank: i + 1,
+                pool_id: *pool_id,
+                stake: *stake,
+                share: *stake as f64 / total.max(1) as f64,
+                saturation: *stake as f64 / saturation_point.max(1) as f64,
+            })
+            .collect()
+    }
+}
+
+/// Progress of the background aggregation of the stake distribution.
+enum StakeProgress {
+    UtxosScanned(usize),
+    Loaded(Arc<StakeDistribution>),
+}
+
+/// The stake distribution, aggregated in the background the first time a
+/// view needs it and shared by all of them. The scan stops once the job is
+/// dropped.
+pub struct StakeDistributionJob {
+    db: Arc<ReadOnlyRocksDB>,
+    provider: Option<AsyncProvider<StakeProgress>>,
+    pub scanned: usize,
+    pub distribution: Option<Arc<StakeDistribution>>,
+    /// Set once the job ended without a distribution.
+    pub failed: bool,
+}
+
+impl StakeDistributionJob {
+    pub fn new(db: Arc<ReadOnlyRocksDB>) -> Self {
+        Self {
+            db,
+            provider: None,
+            scanned: 0,
+            distribution: None,
+            failed: false,
+        }
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    /// Starts the aggregation, unless it already started.
+    pub fn start(&mut self) {
+        if self.provider.is_some() {
+            return;
+        }
+        let db = self.db.clone();
+        self.provider = Some(AsyncProvider::new(move |tx| {
+            let result = StakeDistribution::load(&db, |scanned| {
+                tx.blocking_send(StakeProgress::UtxosScanned(scanned))
+                    .is_ok()
+            });
+            match result {
+                Ok(Some(distribution)) => {
+                    let _ = tx.blocking_send(StakeProgress::Loaded(Arc::new(distribution)));
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Failed to compute the stake distribution: {}", e),
+            }
+        }));
+    }
+
+    /// Collects the progress of the aggregation. Returns the distribution
+    /// when it was just loaded.
+    pub fn poll(&mut self) -> Option<Arc<StakeDistribution>> {
+        if self.distribution.is_some() || self.failed {
+            return None;
+        }
+        let provider = self.provider.as_mut()?;
+        loop {
+            match provider.rx.try_recv() {
+                Ok(StakeProgress::UtxosScanned(scanned)) => self.scanned = scanned,
+                Ok(StakeProgress::Loaded(distribution)) => {
+                    self.distribution = Some(distribution.clone());
+                    return Some(distribution);
+                }
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    self.failed = true;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// A pool's place in the stake distribution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolStake {
+    pub rank: usize,
+    pub pool_id: PoolId,
+    pub stake: u64,
+    /// Share of the delegated stake, between 0 and 1.
+    pub share: f64,
+    /// Stake relative to the saturation point, above 1 once saturated.
+    pub saturation: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_pools() {
+        let distribution = StakeDistribution {
+            pools: HashMap::from([
+                (PoolId::from([1; 28]), 100),
+                (PoolId::from([2; 28]), 300),
+                (PoolId::from([3; 28]), 600),
+            ]),
+            ..StakeDistribution::default()
+        };
+
+        let ranked = distribution.rank_pools(500);
+        assert_eq!(
+            ranked.iter().map(|p| p.stake).collect::<Vec<_>>(),
+            vec![600, 300, 100]
+        );
+        assert_eq!(ranked[0].rank, 1);
+        assert_eq!(ranked[0].share, 0.6);
+        assert_eq!(ranked[0].saturation, 1.2);
+        assert_eq!(ranked[2].saturation, 0.2);
+    }
+
+    #[test]
+    fn test_saturation_point() {
+        let reserves = 7_000_000_000_000_000;
+        assert_eq!(saturation_point(reserves, 500), 76_000_000_000_000);
+        assert_eq!(saturation_point(reserves, 0), 38_000_000_000_000_000);
+        assert_eq!(saturation_point(MAX_LOVELACE_SUPPLY + 1, 500), 0);
+    }
+}
@@ -0,0 +1,108 @@
+/// Width and visibility of the columns of a table view, with a cursor on the
+/// column being resized or hidden.
+#[derive(Debug)]
+pub struct TableColumns {
+    pub headers: Vec<&'static str>,
+    pub widths: Vec<u16>,
+    pub hidden: Vec<bool>,
+    pub selected: usize,
+}
+
+const MIN_WIDTH: u16 = 3;
+const WIDTH_STEP: u16 = 2;
+
+impl TableColumns {
+    pub fn new(columns: &[(&'static str, u16)]) -> Self {
+        Self {
+            headers: columns.iter().map(|(header, _)| *header).collect(),
+            widths: columns.iter().map(|(_, width)| *width).collect(),
+            hidden: vec![false; columns.len()],
+            selected: 0,
+        }
+    }
+
+    /// Indices of the columns to draw.
+    pub fn visible(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.headers.len()).filter(|i| !self.hidden[*i])
+    }
+
+    pub fn select_next(&mut self) {
+        let next = self.visible().find(|i| *i > self.selected);
+        if let Some(next) = next {
+            self.selected = next;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        let prev = self.visible().filter(|i| *i < self.selected).last();
+        if let Some(prev) = prev {
+            self.selected = prev;
+        }
+    }
+
+    pub fn widen(&mut self) {
+        if let Some(width) = self.widths.get_mut(self.selected) {
+            *width = width.saturating_add(WIDTH_STEP);
+        }
+    }
+
+    pub fn narrow(&mut self) {
+        if let Some(width) = self.widths.get_mut(self.selected) {
+            *width = width.saturating_sub(WIDTH_STEP).max(MIN_WIDTH);
+        }
+    }
+
+    /// Hides the selected column, unless it is the last one visible, and moves
+    /// the cursor to a visible neighbour.
+    pub fn hide_selected(&mut self) {
+        if self.visible().count() <= 1 {
+            return;
+        }
+        self.hidden[self.selected] = true;
+        let next = self
+            .visible()
+            .find(|i| *i > self.selected)
+            .or_else(|| self.visible().last());
+        if let Some(next) = next {
+            self.selected = next;
+        }
+    }
+
+    pub fn show_all(&mut self) {
+        self.hidden.fill(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hide_and_navigate() {
+        let mut columns = TableColumns::new(&[("A", 5), ("B", 5), ("C", 5)]);
+        columns.select_next();
+        columns.hide_selected();
+        assert_eq!(columns.visible().collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(columns.selected, 2);
+
+        columns.hide_selected();
+        assert_eq!(columns.selected, 0);
+        columns.hide_selected();
+        assert_eq!(columns.visible().collect::<Vec<_>>(), vec![0]);
+
+        columns.show_all();
+        columns.select_next();
+        assert_eq!(columns.selected, 1);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut columns = TableColumns::new(&[("A", 4)]);
+        columns.widen();
+        assert_eq!(columns.widths[0], 6);
+        columns.narrow();
+        columns.narrow();
+        columns.narrow();
+        assert_eq!(columns.widths[0], MIN_WIDTH);
+    }
+}
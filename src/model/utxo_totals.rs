@@ -0,0 +1,145 @@
+use crate::{states::ComponentId, ui::to_list_item::UtxoItem};
+use amaru_kernel::{Bytes, Hash, MemoizedDatum, Value, to_cbor};
+use std::collections::BTreeMap;
+
+/// Running aggregates over a stream of UTXOs, updated as search results
+/// arrive.
+#[derive(Debug, Default)]
+pub struct UtxoTotals {
+    /// Identifies the result set the totals were computed for, so they can be
+    /// reset when the search changes.
+    source: Option<(ComponentId, usize)>,
+    /// How many items of the result set were already aggregated.
+    seen: usize,
+    pub is_loading: bool,
+    pub count: usize,
+    pub lovelace: u64,
+    pub assets: BTreeMap<(Hash<28>, Bytes), u128>,
+    pub with_datum: usize,
+    pub with_script_ref: usize,
+    /// Min and max CBOR size of the outputs, in bytes.
+    pub min_size: Option<usize>,
+    pub max_size: Option<usize>,
+}
+
+impl UtxoTotals {
+    /// Aggregates the items not seen yet. `source` identifies the result set;
+    /// when it changes the totals start over.
+    pub fn update(&mut self, source: (ComponentId, usize), items: &[UtxoItem], is_loading: bool) {
+        if self.source != Some(source) || items.len() < self.seen {
+            *self = Self {
+                source: Some(source),
+                ..Self::default()
+            };
+        }
+        self.is_loading = is_loading;
+
+        for (_, output) in &items[self.seen..] {
+            self.count += 1;
+            match &output.value {
+                Value::Coin(coin) => self.lovelace += coin,
+                Value::Multiasset(coin, assets) => {
+                    self.lovelace += coin;
+                    for (policy, aset) in assets.iter() {
+                        for (name, amount) in aset.iter() {
+                            *self.assets.entry((*policy, name.clone())).or_default() +=
+                                u64::from(*amount) as u128;
+                        }
+                    }
+                }
+            }
+            if !matches!(output.datum, MemoizedDatum::None) {
+                self.with_datum += 1;
+            }
+            if output.script.is_some() {
+                self.with_script_ref += 1;
+            }
+            let size = to_cbor(output).len();
+            self.min_size = Some(self.min_size.map_or(size, |min| min.min(size)));
+            self.max_size = Some(self.max_size.map_or(size, |max| max.max(size)));
+        }
+        self.seen = items.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use amaru_kernel::{MemoizedTransactionOutput, TransactionInput};
+    use std::str::FromStr;
+
+    /// A legacy output holding 1 ada, to an enterprise address.
+    const ADA_ONLY: &str =
+        "82581d61000000000000000000000000000000000000000000000000000000001a000f4240";
+
+    /// A post-Alonzo output holding 2 ada and 5 `ABC` tokens, with a datum
+    /// hash.
+    const WITH_ASSETS: &str = concat!(
+        "a300581d610000000000000000000000000000000000000000000000000000000001821a001e8480",
+        "a1581c11111111111111111111111111111111111111111111111111111111a14341424305028200",
+        "58202222222222222222222222222222222222222222222222222222222222222222",
+    );
+
+    fn utxo(index: u64, output: &str) -> UtxoItem {
+        let input = TransactionInput {
+            transaction_id: Hash::<32>::from_str(
+                "2e6b2226fd74ab0cadc53aaa18759752752bd9b616ea48c0e7b7be77d1af4bf4",
+            )
+            .unwrap(),
+            index,
+        };
+        let output =
+            minicbor::decode::<MemoizedTransactionOutput>(&hex::decode(output).unwrap()).unwrap();
+        (input, output)
+    }
+
+    #[test]
+    fn test_update_aggregates_new_items() {
+        let items = vec![utxo(0, ADA_ONLY), utxo(1, WITH_ASSETS)];
+        let source = (ComponentId::LedgerUtxosByAddrList, 0);
+        let mut totals = UtxoTotals::default();
+
+        totals.update(source, &items[..1], true);
+        assert!(totals.is_loading);
+        assert_eq!(totals.count, 1);
+        assert_eq!(totals.lovelace, 1_000_000);
+
+        totals.update(source, &items, false);
+        assert!(!totals.is_loading);
+        assert_eq!(totals.count, 2);
+        assert_eq!(totals.lovelace, 3_000_000);
+        assert_eq!(totals.with_datum, 1);
+        assert_eq!(totals.with_script_ref, 0);
+        assert_eq!(totals.assets.values().copied().collect::<Vec<_>>(), vec![5]);
+        assert!(totals.min_size < totals.max_size);
+
+        // Nothing new, nothing is counted twice
+        totals.update(source, &items, false);
+        assert_eq!(totals.count, 2);
+    }
+
+    #[test]
+    fn test_update_resets_on_new_source() {
+        let items = vec![utxo(0, ADA_ONLY), utxo(1, WITH_ASSETS)];
+        let mut totals = UtxoTotals::default();
+        totals.update((ComponentId::LedgerUtxosByAddrList, 0), &items, false);
+
+        // A new search of the same list
+        totals.update((ComponentId::LedgerUtxosByAddrList, 1), &items[1..], true);
+        assert!(totals.is_loading);
+        assert_eq!(totals.count, 1);
+        assert_eq!(totals.lovelace, 2_000_000);
+
+        // Another list
+        totals.update((ComponentId::LedgerUtxosByStakeList, 1), &items[..1], false);
+        assert_eq!(totals.count, 1);
+        assert_eq!(totals.lovelace, 1_000_000);
+        assert!(totals.assets.is_empty());
+        assert_eq!(totals.with_datum, 0);
+
+        // The same source restarting with fewer items
+        totals.update((ComponentId::LedgerUtxosByStakeList, 1), &[], true);
+        assert_eq!(totals.count, 0);
+        assert_eq!(totals.min_size, None);
+    }
+}
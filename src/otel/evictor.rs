@@ -13,16 +13,35 @@ pub struct Evictor {
 
     /// Parameter that determines after how long a Trace should be evicted.
     expire_duration: Duration,
+
+    /// The most spans the graph holds before its oldest Traces are evicted.
+    max_spans: usize,
+
+    /// The most memory, approximately, the graph's spans take before its
+    /// oldest Traces are evicted.
+    max_bytes: usize,
 }
 
 impl Evictor {
-    pub fn new(expire_duration: Duration) -> Self {
+    pub fn new(expire_duration: Duration, max_spans: usize, max_bytes: usize) -> Self {
         Self {
             start_to_trace: BTreeMap::new(),
             expire_duration,
+            max_spans,
+            max_bytes,
         }
     }
 
+    /// The spans and bytes above the budgets, counting the orphans.
+    fn excess(&self, graph: &TraceGraph, orphanage: &Orphanage) -> (usize, usize) {
+        let spans = graph.spans.len() + orphanage.span_count();
+        let bytes = graph.approx_published_bytes() + orphanage.approx_bytes();
+        (
+            spans.saturating_sub(self.max_spans),
+            bytes.saturating_sub(self.max_bytes),
+        )
+    }
+
     /// Updates the tracking for a given trace for both new traces and existing
     /// traces whose start time has changed.
     pub fn update_trace_lifetime(&mut self, info: TraceInfo) {
@@ -32,9 +51,33 @@ impl Evictor {
         self.track(info.trace_id, info.new_trace_start);
     }
 
-    /// Finds expired traces and removes them from the graph.
+    /// Finds expired traces, then the oldest orphans and traces while the
+    /// store is over its span or memory budget, and removes them.
     /// Returns the list of evicted trace IDs.
     pub fn evict(&mut self, graph: &mut TraceGraph, orphanage: &mut Orphanage) -> Vec<TraceId> {
+        let mut evicted_ids = self.evict_expired(graph, orphanage);
+
+        // Orphans are not shown until their parent arrives, so they go first.
+        let (spans, bytes) = self.excess(graph, orphanage);
+        if spans > 0 || bytes > 0 {
+            orphanage.evict_oldest(spans, bytes);
+        }
+
+        // Traces are evicted whole, so the graph may end up well under budget.
+        while self.excess(graph, orphanage) != (0, 0) {
+            let Some((_, trace_ids)) = self.start_to_trace.pop_first() else {
+                break;
+            };
+            for trace_id in trace_ids {
+                Self::remove(graph, &trace_id);
+                evicted_ids.push(trace_id);
+            }
+        }
+
+        evicted_ids
+    }
+
+    fn evict_expired(&mut self, graph: &mut TraceGraph, orphanage: &mut Orphanage) -> Vec<TraceId> {
         let expire_before = match SystemTime::now().checked_sub(self.expire_duration) {
             Some(time) => time,
             // If time calculation fails, no traces can be expired.
@@ -60,13 +103,20 @@ impl Evictor {
 
         // Remove the expired items from the graph.
         for trace_id in &evicted_ids {
-            graph.remove_trace(trace_id);
+            Self::remove(graph, trace_id);
         }
 
         // Return the collected list of expired IDs.
         evicted_ids
     }
 
+    /// Removes a trace from the graph, counting it as evicted.
+    fn remove(graph: &mut TraceGraph, trace_id: &TraceId) {
+        let span_ids = graph.remove_trace(trace_id);
+        graph.evicted.traces += 1;
+        graph.evicted.spans += span_ids.len() as u64;
+    }
+
     /// Starts tracking a trace.
     fn track(&mut self, trace_id: TraceId, start_time: SystemTime) {
         self.start_to_trace
@@ -88,3 +138,115 @@ impl Evictor {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::otel::{graph::EvictionCounts, id::Id, span_ext::SpanExt};
+    use opentelemetry_proto::tonic::trace::v1::Span;
+
+    /// A root span starting `start` seconds after now, with `children`.
+    fn add_trace(evictor: &mut Evictor, graph: &mut TraceGraph, id: u8, start: u64, children: u8) {
+        let start = SystemTime::now() + Duration::from_secs(start);
+        let nanos = start
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+        let span = |span_id: u8, parent: Option<u8>| Span {
+            trace_id: vec![id; 16],
+            span_id: vec![id, span_id, 0, 0, 0, 0, 0, 0],
+            parent_span_id: parent.map_or(Vec::new(), |p| vec![id, p, 0, 0, 0, 0, 0, 0]),
+            start_time_unix_nano: nanos,
+            end_time_unix_nano: nanos + 1_000,
+            ..Span::default()
+        };
+        evictor.update_trace_lifetime(graph.insert_root_span(span(0, None)));
+        for child in 1..=children {
+            graph.insert_child_span(span(child, Some(0)));
+        }
+    }
+
+    #[test]
+    fn test_evict_over_span_budget() {
+        let mut evictor = Evictor::new(Duration::MAX, 5, usize::MAX);
+        let mut graph = TraceGraph::default();
+        add_trace(&mut evictor, &mut graph, 1, 1, 2);
+        add_trace(&mut evictor, &mut graph, 2, 2, 2);
+        assert_eq!(graph.spans.len(), 6);
+
+        let evicted = evictor.evict(&mut graph, &mut Orphanage::default());
+        assert_eq!(evicted, vec![Id([1; 16])]);
+        assert_eq!(graph.spans.len(), 3);
+        assert_eq!(
+            graph.evicted,
+            EvictionCounts {
+                traces: 1,
+                spans: 3
+            }
+        );
+    }
+
+    /// A span of trace `id` whose parent did not arrive, starting `start`
+    /// seconds after now.
+    fn orphan(id: u8, span_id: u8, start: u64) -> Span {
+        let start = SystemTime::now() + Duration::from_secs(start);
+        Span {
+            trace_id: vec![id; 16],
+            span_id: vec![id, span_id, 0, 0, 0, 0, 0, 0],
+            parent_span_id: vec![id, 0xff, 0, 0, 0, 0, 0, 0],
+            start_time_unix_nano: start
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos() as u64,
+            ..Span::default()
+        }
+    }
+
+    #[test]
+    fn test_evict_orphans_first() {
+        let mut evictor = Evictor::new(Duration::MAX, 4, usize::MAX);
+        let mut graph = TraceGraph::default();
+        let mut orphanage = Orphanage::default();
+        add_trace(&mut evictor, &mut graph, 1, 1, 1);
+        for (span_id, start) in [(1, 3), (2, 1), (3, 2)] {
+            let span = orphan(2, span_id, start);
+            orphanage.add(span.parent_id().unwrap(), span);
+        }
+        assert_eq!(orphanage.span_count(), 3);
+
+        // The oldest orphan goes, the trace stays
+        assert!(evictor.evict(&mut graph, &mut orphanage).is_empty());
+        assert_eq!(orphanage.span_count(), 2);
+        assert_eq!(graph.spans.len(), 2);
+        let orphans = orphanage.remove(&Id([2, 0xff, 0, 0, 0, 0, 0, 0])).unwrap();
+        assert_eq!(
+            orphans.iter().map(|o| o.span_id[1]).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(orphanage.span_count(), 0);
+        assert_eq!(orphanage.approx_bytes(), 0);
+    }
+
+    #[test]
+    fn test_evict_over_memory_budget() {
+        let mut graph = TraceGraph::default();
+        let mut evictor = Evictor::new(Duration::MAX, usize::MAX, 0);
+        add_trace(&mut evictor, &mut graph, 1, 1, 1);
+        let one_trace = graph.approx_bytes();
+        evictor.max_bytes = graph.approx_published_bytes();
+        add_trace(&mut evictor, &mut graph, 2, 2, 1);
+        add_trace(&mut evictor, &mut graph, 3, 3, 1);
+
+        evictor.evict(&mut graph, &mut Orphanage::default());
+        assert_eq!(graph.traces.len(), 1);
+        assert!(graph.traces.contains_key(&Id([3; 16])));
+        assert_eq!(graph.approx_bytes(), one_trace);
+        assert_eq!(
+            graph.evicted,
+            EvictionCounts {
+                traces: 2,
+                spans: 4
+            }
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::otel::span_ext::SpanExt;
 use crate::otel::trace_iter::TraceIter;
 use crate::otel::{SubTree, TraceMeta};
 use opentelemetry_proto::tonic::trace::v1::Span;
+use prost::Message;
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::SystemTime;
@@ -17,6 +18,26 @@ pub struct TraceInfo {
     pub new_trace_start: SystemTime,
 }
 
+/// What a span costs in the graph besides its own encoding: its entries in
+/// `spans` and `subtrees`, its SubTree and the entry in its parent's children.
+const SPAN_OVERHEAD_BYTES: usize = 256;
+
+/// What a span costs in each published snapshot: a copy of its entries in
+/// the graph's maps, the spans and SubTrees themselves being shared.
+const SNAPSHOT_SPAN_BYTES: usize = 64;
+
+/// The approximate memory a span takes in the graph.
+pub fn approx_span_bytes(span: &Span) -> usize {
+    span.encoded_len() + SPAN_OVERHEAD_BYTES
+}
+
+/// The traces and spans evicted from the graph since the collector started.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EvictionCounts {
+    pub traces: u64,
+    pub spans: u64,
+}
+
 /// Holds the state of processed traces, nodes, and spans.
 #[derive(Clone, Debug, Default)]
 pub struct TraceGraph {
@@ -30,6 +51,11 @@ pub struct TraceGraph {
     /// A 1-1 map of TraceId to TraceMeta. TraceMeta holds the known RootIds for
     /// the Trace.
     pub traces: HashMap<TraceId, Arc<TraceMeta>>,
+
+    /// The approximate memory of the spans, see `approx_span_bytes`.
+    bytes: usize,
+
+    pub evicted: EvictionCounts,
 }
 
 impl TraceGraph {
@@ -57,7 +83,7 @@ impl TraceGraph {
         // Insert the full span and SubTree data into the main HashMaps.
         self.subtrees
             .insert(new_root_id, SubTree::new(new_root_start, new_root_end));
-        self.spans.insert(new_root_id, Arc::new(span));
+        self.insert_span(new_root_id, span);
 
         // Return the info. We can safely unwrap the new start time because
         // we know we just added a root, so the map inside TraceMeta can't be empty.
@@ -91,11 +117,30 @@ impl TraceGraph {
         self.subtrees
             .insert(span_id, SubTree::new(start_time, end_time));
         // Add the span's details
-        self.spans.insert(span_id, Arc::new(span));
+        self.insert_span(span_id, span);
         // Update subtree bounds with this newly added, potentially later end time
         self.propagate_bounds_update(span_id, end_time);
     }
 
+    /// The approximate memory the spans take.
+    pub fn approx_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// The approximate memory the spans take, counting the copy of the maps
+    /// kept by the published snapshot.
+    pub fn approx_published_bytes(&self) -> usize {
+        self.bytes + self.spans.len() * SNAPSHOT_SPAN_BYTES
+    }
+
+    fn insert_span(&mut self, span_id: SpanId, span: Span) {
+        self.bytes += approx_span_bytes(&span);
+        // A span sent twice replaces the previous copy
+        if let Some(previous) = self.spans.insert(span_id, Arc::new(span)) {
+            self.bytes = self.bytes.saturating_sub(approx_span_bytes(&previous));
+        }
+    }
+
     /// When a child's end time is later than its parent's, this walks up the
     /// tree to ensure all ancestor bounds are updated with the new end time
     pub fn propagate_bounds_update(&mut self, start_span_id: SpanId, new_end_time: SystemTime) {
@@ -133,7 +178,9 @@ impl TraceGraph {
         // Remove the entries from the maps.
         for span_id in &ids_to_remove {
             self.subtrees.remove(span_id);
-            self.spans.remove(span_id);
+            if let Some(span) = self.spans.remove(span_id) {
+                self.bytes = self.bytes.saturating_sub(approx_span_bytes(&span));
+            }
         }
 
         // Remove the top-level trace metadata.
@@ -0,0 +1,196 @@
+use axum::{
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use flate2::read::GzDecoder;
+use prost::Message;
+use serde::de::DeserializeOwned;
+use std::io::Read;
+use tracing::warn;
+
+/// The largest request body accepted once decompressed. Repetitive spans
+/// compress well, a small gzipped body can expand a lot.
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// The encodings of an OTLP/HTTP request body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OtlpEncoding {
+    Protobuf,
+    Json,
+}
+
+impl OtlpEncoding {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if content_type.starts_with("application/json") {
+            Self::Json
+        } else {
+            Self::Protobuf
+        }
+    }
+
+    /// A successful export response. All export responses are empty
+    /// messages when nothing was rejected.
+    pub fn ok(self) -> Response {
+        match self {
+            Self::Protobuf => (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "application/x-protobuf")],
+                Bytes::new(),
+            )
+                .into_response(),
+            Self::Json => (
+                StatusCode::OK,
+                [(header::CONTENT_TYPE, "application/json")],
+                "{}",
+            )
+                .into_response(),
+        }
+    }
+}
+
+fn decompress(headers: &HeaderMap, body: Bytes, max_body: usize) -> Result<Bytes, StatusCode> {
+    let encoding = headers
+        .get(header::CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("identity");
+    match encoding {
+        "identity" => Ok(body),
+        "gzip" => {
+            let mut decompressed = Vec::new();
+            GzDecoder::new(body.as_ref())
+                .take(max_body as u64 + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(|e| {
+                    warn!("Invalid gzip body: {}", e);
+                    StatusCode::BAD_REQUEST
+                })?;
+            if decompressed.len() > max_body {
+                warn!("Request body above {} bytes once decompressed", max_body);
+                return Err(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+            Ok(decompressed.into())
+        }
+        other => {
+            warn!("Unsupported content encoding: {}", other);
+            Err(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+        }
+    }
+}
+
+/// Decodes an OTLP/HTTP export request, encoded as protobuf or JSON depending
+/// on its content type, and optionally gzipped. Decoding runs on the blocking
+/// pool, a large request would otherwise stall the runtime.
+pub async fn decode_request<T>(
+    headers: &HeaderMap,
+    body: Bytes,
+) -> Result<(T, OtlpEncoding), StatusCode>
+where
+    T: Message + Default + DeserializeOwned + Send + 'static,
+{
+    let headers = headers.clone();
+    tokio::task::spawn_blocking(move || decode_body(&headers, body))
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to decode an export request: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        })
+}
+
+fn decode_body<T>(headers: &HeaderMap, body: Bytes) -> Result<(T, OtlpEncoding), StatusCode>
+where
+    T: Message + Default + DeserializeOwned,
+{
+    let body = decompress(headers, body, MAX_BODY_BYTES)?;
+    let encoding = OtlpEncoding::from_headers(headers);
+    let request = match encoding {
+        OtlpEncoding::Protobuf => T::decode(body.as_ref()).map_err(|e| e.to_string()),
+        OtlpEncoding::Json => serde_json::from_slice(&body).map_err(|e| e.to_string()),
+    };
+    match request {
+        Ok(request) => Ok((request, encoding)),
+        Err(e) => {
+            warn!("Invalid {:?} export request: {}", encoding, e);
+            Err(StatusCode::BAD_REQUEST)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use flate2::{Compression, write::GzEncoder};
+    use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+    use std::io::Write;
+
+    #[test]
+    fn test_decompress() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"spans").unwrap();
+        let gzipped = Bytes::from(encoder.finish().unwrap());
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(decompress(&headers, gzipped.clone(), 5).unwrap(), gzipped);
+
+        headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        assert_eq!(
+            decompress(&headers, gzipped.clone(), 5).unwrap(),
+            Bytes::from("spans")
+        );
+        assert_eq!(
+            decompress(&headers, gzipped, 4),
+            Err(StatusCode::PAYLOAD_TOO_LARGE)
+        );
+
+        headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static("br"));
+        assert_eq!(
+            decompress(&headers, Bytes::new(), 5),
+            Err(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+        );
+    }
+
+    #[test]
+    fn test_decode_json_body() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        let body = Bytes::from_static(
+            br#"{"resourceSpans":[{"scopeSpans":[{"spans":[{
+            "traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174",
+            "name":"roll_forward","startTimeUnixNano":"1000","endTimeUnixNano":"3000"}]}]}]}"#,
+        );
+
+        let (request, encoding) = decode_body::<ExportTraceServiceRequest>(&headers, body).unwrap();
+        assert_eq!(encoding, OtlpEncoding::Json);
+        let span = &request.resource_spans[0].scope_spans[0].spans[0];
+        assert_eq!(span.name, "roll_forward");
+        assert_eq!(
+            span.span_id,
+            vec![0xee, 0xe1, 0x9b, 0x7e, 0xc3, 0xc1, 0xb1, 0x74]
+        );
+        assert_eq!(span.end_time_unix_nano, 3000);
+
+        assert_eq!(
+            decode_body::<ExportTraceServiceRequest>(&headers, Bytes::from_static(b"{")),
+            Err(StatusCode::BAD_REQUEST)
+        );
+    }
+
+    #[test]
+    fn test_encoding_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(OtlpEncoding::from_headers(&headers), OtlpEncoding::Protobuf);
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        );
+        assert_eq!(OtlpEncoding::from_headers(&headers), OtlpEncoding::Json);
+    }
+}
@@ -0,0 +1,244 @@
+use anyhow::{Context, Result, anyhow, bail};
+use opentelemetry_proto::tonic::{
+    common::v1::{AnyValue, KeyValue, any_value},
+    trace::v1::Span,
+};
+use serde_json::Value;
+use std::{fs, path::Path};
+
+/// Reads the spans of a trace file exported by another tool: either OTLP/JSON,
+/// as written by the OpenTelemetry collector file exporter, or a Jaeger JSON
+/// export. The format is detected from the content.
+pub fn load_trace_file(path: &Path) -> Result<Vec<Span>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Unable to read the trace file {}", path.display()))?;
+    parse_traces(&content).with_context(|| format!("Invalid trace file {}", path.display()))
+}
+
+/// Parses a sequence of JSON documents, the file exporter writing one export
+/// request per line.
+fn parse_traces(content: &str) -> Result<Vec<Span>> {
+    let mut spans = Vec::new();
+    for document in serde_json::Deserializer::from_str(content).into_iter::<Value>() {
+        let document = document?;
+        if document.get("resourceSpans").is_some() {
+            spans.extend(otlp_spans(&document)?);
+        } else if document.get("data").is_some() {
+            spans.extend(jaeger_spans(&document)?);
+        } else {
+            bail!("Neither an OTLP/JSON nor a Jaeger JSON document");
+        }
+    }
+    Ok(spans)
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Result<&'a str> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Missing {}", key))
+}
+
+/// A number, which JSON exports write either as a number or, for 64 bits
+/// integers, as a string.
+fn u64_field(value: &Value, key: &str) -> Result<u64> {
+    match value.get(key) {
+        Some(Value::Number(n)) => n.as_u64().ok_or_else(|| anyhow!("Invalid {}: {}", key, n)),
+        Some(Value::String(s)) => s.parse().with_context(|| format!("Invalid {}: {}", key, s)),
+        _ => bail!("Missing {}", key),
+    }
+}
+
+/// Decodes a hex id, left-padded with zeros to `len` bytes as Jaeger may
+/// shorten trace ids to 64 bits.
+fn hex_id(hex_str: &str, len: usize) -> Result<Vec<u8>> {
+    let bytes = hex::decode(hex_str).with_context(|| format!("Invalid id {}", hex_str))?;
+    if bytes.is_empty() || bytes.len() > len {
+        bail!("Invalid id {}, expected {} bytes", hex_str, len);
+    }
+    let mut id = vec![0; len - bytes.len()];
+    id.extend(bytes);
+    Ok(id)
+}
+
+fn key_value(key: &str, value: any_value::Value) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue { value: Some(value) }),
+    }
+}
+
+/// An OTLP/JSON attribute value. Arrays and maps are kept as their JSON text.
+fn otlp_value(value: &Value) -> Option<any_value::Value> {
+    let (kind, inner) = value.as_object()?.iter().next()?;
+    Some(match kind.as_str() {
+        "stringValue" => any_value::Value::StringValue(inner.as_str()?.to_string()),
+        "boolValue" => any_value::Value::BoolValue(inner.as_bool()?),
+        "intValue" => any_value::Value::IntValue(match inner {
+            Value::String(s) => s.parse().ok()?,
+            other => other.as_i64()?,
+        }),
+        "doubleValue" => any_value::Value::DoubleValue(inner.as_f64()?),
+        _ => any_value::Value::StringValue(inner.to_string()),
+    })
+}
+
+fn otlp_span(span: &Value) -> Result<Span> {
+    let parent = span
+        .get("parentSpanId")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    Ok(Span {
+        trace_id: hex_id(str_field(span, "traceId")?, 16)?,
+        span_id: hex_id(str_field(span, "spanId")?, 8)?,
+        parent_span_id: if parent.is_empty() {
+            Vec::new()
+        } else {
+            hex_id(parent, 8)?
+        },
+        name: str_field(span, "name").unwrap_or_default().to_string(),
+        start_time_unix_nano: u64_field(span, "startTimeUnixNano")?,
+        end_time_unix_nano: u64_field(span, "endTimeUnixNano")?,
+        attributes: array(span, "attributes")
+            .iter()
+            .filter_map(|attribute| {
+                let key = attribute.get("key")?.as_str()?;
+                Some(key_value(key, otlp_value(attribute.get("value")?)?))
+            })
+            .collect(),
+        ..Span::default()
+    })
+}
+
+fn otlp_spans(request: &Value) -> Result<Vec<Span>> {
+    array(request, "resourceSpans")
+        .iter()
+        .flat_map(|resource| array(resource, "scopeSpans"))
+        .flat_map(|scope| array(scope, "spans"))
+        .map(otlp_span)
+        .collect()
+}
+
+fn jaeger_value(tag: &Value) -> Option<any_value::Value> {
+    let value = tag.get("value")?;
+    Some(match tag.get("type").and_then(Value::as_str) {
+        Some("bool") => any_value::Value::BoolValue(value.as_bool()?),
+        Some("int64") => any_value::Value::IntValue(value.as_i64()?),
+        Some("float64") => any_value::Value::DoubleValue(value.as_f64()?),
+        _ => match value {
+            Value::String(s) => any_value::Value::StringValue(s.clone()),
+            other => any_value::Value::StringValue(other.to_string()),
+        },
+    })
+}
+
+/// Jaeger times are in microseconds, and the parent is the `CHILD_OF`
+/// reference of a span.
+fn jaeger_span(span: &Value) -> Result<Span> {
+    let parent = array(span, "references")
+        .iter()
+        .find(|reference| reference.get("refType").and_then(Value::as_str) == Some("CHILD_OF"));
+    let start = u64_field(span, "startTime")?.saturating_mul(1_000);
+    let duration = u64_field(span, "duration")?.saturating_mul(1_000);
+    Ok(Span {
+        trace_id: hex_id(str_field(span, "traceID")?, 16)?,
+        span_id: hex_id(str_field(span, "spanID")?, 8)?,
+        parent_span_id: match parent {
+            Some(reference) => hex_id(str_field(reference, "spanID")?, 8)?,
+            None => Vec::new(),
+        },
+        name: str_field(span, "operationName")
+            .unwrap_or_default()
+            .to_string(),
+        start_time_unix_nano: start,
+        end_time_unix_nano: start.saturating_add(duration),
+        attributes: array(span, "tags")
+            .iter()
+            .filter_map(|tag| {
+                let key = tag.get("key")?.as_str()?;
+                Some(key_value(key, jaeger_value(tag)?))
+            })
+            .collect(),
+        ..Span::default()
+    })
+}
+
+fn jaeger_spans(export: &Value) -> Result<Vec<Span>> {
+    array(export, "data")
+        .iter()
+        .flat_map(|trace| array(trace, "spans"))
+        .map(jaeger_span)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_id() {
+        assert_eq!(hex_id("0a0b", 4).unwrap(), vec![0, 0, 10, 11]);
+        assert!(hex_id("", 4).is_err());
+        assert!(hex_id("0102030405", 4).is_err());
+        assert!(hex_id("zz", 4).is_err());
+    }
+
+    #[test]
+    fn test_parse_otlp_json() {
+        let content = r#"{"resourceSpans":[{"scopeSpans":[{"spans":[{
+            "traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174",
+            "parentSpanId":"","name":"roll_forward",
+            "startTimeUnixNano":"1000","endTimeUnixNano":"3000",
+            "attributes":[{"key":"slot","value":{"intValue":"42"}}]}]}]}]}
+            {"resourceSpans":[{"scopeSpans":[{"spans":[{
+            "traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b175",
+            "parentSpanId":"eee19b7ec3c1b174","name":"validate",
+            "startTimeUnixNano":1500,"endTimeUnixNano":2000}]}]}]}"#;
+
+        let spans = parse_traces(content).unwrap();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].name, "roll_forward");
+        assert!(spans[0].parent_span_id.is_empty());
+        assert_eq!(spans[0].end_time_unix_nano, 3000);
+        assert_eq!(
+            spans[0].attributes[0].value,
+            Some(AnyValue {
+                value: Some(any_value::Value::IntValue(42))
+            })
+        );
+        assert_eq!(spans[1].parent_span_id, spans[0].span_id);
+    }
+
+    #[test]
+    fn test_parse_jaeger_json() {
+        let content = r#"{"data":[{"traceID":"d269b633813fc60c","spans":[{
+            "traceID":"d269b633813fc60c","spanID":"eee19b7ec3c1b174",
+            "operationName":"roll_forward",
+            "references":[{"refType":"CHILD_OF","traceID":"d269b633813fc60c","spanID":"eee19b7ec3c1b173"}],
+            "startTime":10,"duration":5,
+            "tags":[{"key":"ok","type":"bool","value":true}]}]}]}"#;
+
+        let spans = parse_traces(content).unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].trace_id.len(), 16);
+        assert_eq!(
+            spans[0].parent_span_id,
+            hex_id("eee19b7ec3c1b173", 8).unwrap()
+        );
+        assert_eq!(spans[0].start_time_unix_nano, 10_000);
+        assert_eq!(spans[0].end_time_unix_nano, 15_000);
+    }
+
+    #[test]
+    fn test_parse_unknown_format() {
+        assert!(parse_traces(r#"{"spans":[]}"#).is_err());
+    }
+}
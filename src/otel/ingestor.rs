@@ -20,12 +20,18 @@ pub struct TraceIngestor {
 }
 
 impl TraceIngestor {
-    pub fn new(queue_cap: usize, expire_duration: Duration) -> Self {
+    pub fn new(
+        queue_cap: usize,
+        expire_duration: Duration,
+        max_spans: usize,
+        max_bytes: usize,
+    ) -> Self {
         let (tx, rx) = mpsc::channel(queue_cap);
         let snapshot = Arc::new(ArcSwap::from_pointee(TraceGraph::default()));
 
         // Create and spawn the encapsulated processor.
-        let processor = TraceProcessor::new(rx, snapshot.clone(), expire_duration);
+        let processor =
+            TraceProcessor::new(rx, snapshot.clone(), expire_duration, max_spans, max_bytes);
         tokio::spawn(processor.run());
 
         Self {
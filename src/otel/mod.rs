@@ -8,10 +8,13 @@ use std::time::{Duration, SystemTime};
 pub mod ancestor_iter;
 pub mod evictor;
 pub mod graph;
+pub mod http;
 pub mod id;
+pub mod import;
 pub mod ingestor;
 pub mod orphanage;
 pub mod processor;
+pub mod recording;
 pub mod service;
 pub mod span_ext;
 pub mod store;
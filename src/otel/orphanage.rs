@@ -1,4 +1,5 @@
 use crate::otel::SpanId;
+use crate::otel::graph::approx_span_bytes;
 use crate::otel::span_ext::SpanExt;
 use opentelemetry_proto::tonic::trace::v1::Span;
 use std::collections::HashMap;
@@ -9,12 +10,20 @@ use std::time::SystemTime;
 pub struct Orphanage {
     /// A 1-Many map of parent_ids to orphan Spans
     parent_to_orphans: HashMap<SpanId, Vec<Span>>,
+
+    /// The number of orphan spans.
+    spans: usize,
+
+    /// The approximate memory of the orphans, see `approx_span_bytes`.
+    bytes: usize,
 }
 
 impl Orphanage {
     /// Adds a span that is waiting for its parent to arrive.
     pub fn add(&mut self, parent_id: SpanId, orphan_span: Span) {
         // debug!("Will add orphan span: {:?}", DebugSpan(&orphan_span));
+        self.spans += 1;
+        self.bytes += approx_span_bytes(&orphan_span);
         self.parent_to_orphans
             .entry(parent_id)
             .or_default()
@@ -24,15 +33,64 @@ impl Orphanage {
     /// Called when a parent is added to the graph; returns its waiting
     /// children.
     pub fn remove(&mut self, parent_id: &SpanId) -> Option<Vec<Span>> {
-        self.parent_to_orphans.remove(parent_id)
+        let orphans = self.parent_to_orphans.remove(parent_id)?;
+        self.spans -= orphans.len();
+        self.bytes -= orphans.iter().map(approx_span_bytes).sum::<usize>();
+        Some(orphans)
+    }
+
+    /// The number of orphan spans.
+    pub fn span_count(&self) -> usize {
+        self.spans
+    }
+
+    /// The approximate memory the orphans take.
+    pub fn approx_bytes(&self) -> usize {
+        self.bytes
     }
 
     /// Evicts orphans that are too old to be relevant anymore.
     pub fn evict(&mut self, expire_before: SystemTime) {
         // debug!("Will evict orphans before: {:?}", expire_before);
+        self.retain(|orphan| orphan.start_time() >= expire_before);
+    }
+
+    /// Evicts the oldest orphans until at least `spans` spans and `bytes`
+    /// bytes were freed, or none is left.
+    pub fn evict_oldest(&mut self, spans: usize, bytes: usize) {
+        let mut ages: Vec<(SystemTime, usize)> = self
+            .parent_to_orphans
+            .values()
+            .flatten()
+            .map(|orphan| (orphan.start_time(), approx_span_bytes(orphan)))
+            .collect();
+        ages.sort_unstable_by_key(|(start_time, _)| *start_time);
+
+        let mut freed = (0, 0);
+        let mut evict_until = None;
+        for (start_time, size) in ages {
+            if freed.0 >= spans && freed.1 >= bytes {
+                break;
+            }
+            freed = (freed.0 + 1, freed.1 + size);
+            evict_until = Some(start_time);
+        }
+        if let Some(evict_until) = evict_until {
+            self.retain(|orphan| orphan.start_time() > evict_until);
+        }
+    }
+
+    fn retain(&mut self, keep: impl Fn(&Span) -> bool) {
+        let (spans, bytes) = (&mut self.spans, &mut self.bytes);
         self.parent_to_orphans.retain(|_, orphans| {
-            // Retain the orphans not expired
-            orphans.retain(|orphan| orphan.start_time() >= expire_before);
+            orphans.retain(|orphan| {
+                let kept = keep(orphan);
+                if !kept {
+                    *spans -= 1;
+                    *bytes -= approx_span_bytes(orphan);
+                }
+                kept
+            });
             // Retain the entry if it's not empty
             !orphans.is_empty()
         });
@@ -5,6 +5,13 @@ use opentelemetry_proto::tonic::trace::v1::Span;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::time::{Instant, timeout_at};
+
+/// The least time between two snapshots. Each snapshot clones the graph's
+/// maps, which is costly with many spans, so batches arriving in between are
+/// published together. The memory budget counts that copy, see
+/// `TraceGraph::approx_published_bytes`.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_millis(250);
 
 /// The background worker that processes span batches on its queue and updates
 /// the shared data snapshot.
@@ -19,17 +26,40 @@ impl TraceProcessor {
         batch_rx: mpsc::Receiver<Vec<Span>>,
         snapshot: Arc<ArcSwap<TraceGraph>>,
         expire_duration: Duration,
+        max_spans: usize,
+        max_bytes: usize,
     ) -> Self {
         Self {
-            store: TraceStore::new(expire_duration),
+            store: TraceStore::new(expire_duration, max_spans, max_bytes),
             batch_rx,
             snapshot,
         }
     }
 
     pub async fn run(mut self) {
-        // Wait for incoming messages.
-        while let Some(spans) = self.batch_rx.recv().await {
+        // When the last snapshot was published, and whether the store changed
+        // since then.
+        let mut published = Instant::now();
+        let mut pending = false;
+        loop {
+            // Wait for incoming messages, or until a pending change is due.
+            let received = if pending {
+                match timeout_at(published + SNAPSHOT_INTERVAL, self.batch_rx.recv()).await {
+                    Ok(received) => received,
+                    Err(_) => {
+                        self.publish();
+                        published = Instant::now();
+                        pending = false;
+                        continue;
+                    }
+                }
+            } else {
+                self.batch_rx.recv().await
+            };
+            let Some(spans) = received else {
+                break;
+            };
+
             // Process the first batch received.
             self.store.add_spans(spans);
 
@@ -38,11 +68,26 @@ impl TraceProcessor {
                 self.store.add_spans(more_spans);
             }
 
-            // Evict expired spans and update the snapshot.
+            // Evict expired spans, and the oldest ones over budget.
             self.store.evict_expired();
-            let new_snapshot = self.snapshot();
-            self.snapshot.store(Arc::new(new_snapshot));
+            pending = true;
+
+            if published.elapsed() >= SNAPSHOT_INTERVAL {
+                self.publish();
+                published = Instant::now();
+                pending = false;
+            }
         }
+
+        if pending {
+            self.publish();
+        }
+    }
+
+    /// Updates the snapshot with the current state of the store.
+    fn publish(&self) {
+        let new_snapshot = self.snapshot();
+        self.snapshot.store(Arc::new(new_snapshot));
     }
 
     /// Constructs a new snapshot by performing a clone of trace graph state.
@@ -0,0 +1,221 @@
+use crate::otel::{ingestor::TraceIngestor, trace_service::flatten_spans};
+use anyhow::{Context, Result, bail};
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use prost::Message;
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, TrySendError},
+    },
+    thread,
+    time::Duration,
+};
+use tracing::{info, warn};
+
+/// Requests waiting to be written before new ones are dropped.
+const RECORDER_QUEUE: usize = 1_000;
+
+/// The largest message read back from a recording. A corrupt length would
+/// otherwise allocate up to 2^64 bytes.
+const MAX_MESSAGE_LEN: u64 = 64 * 1024 * 1024;
+
+/// Appends the received export requests to a file, each encoded as a
+/// length-delimited protobuf message. Writes happen on a dedicated thread so
+/// the collector never waits on the disk: while the disk lags behind, new
+/// requests are dropped and their spans counted.
+pub struct TraceRecorder {
+    tx: mpsc::SyncSender<Vec<u8>>,
+    dropped_spans: AtomicUsize,
+}
+
+impl TraceRecorder {
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::options()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Unable to open the recording {}", path.display()))?;
+        let path = path.to_path_buf();
+        let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(RECORDER_QUEUE);
+        thread::spawn(move || {
+            let mut writer = BufWriter::new(file);
+            for record in rx {
+                // Flushed per request, so a crash loses at most the last one
+                if let Err(e) = writer.write_all(&record).and_then(|_| writer.flush()) {
+                    warn!("Failed to write to the recording {}: {}", path.display(), e);
+                    break;
+                }
+            }
+        });
+        info!("Recording traces to {}", path.display());
+        Ok(Self {
+            tx,
+            dropped_spans: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn record(&self, request: &ExportTraceServiceRequest) {
+        if let Err(TrySendError::Full(_)) =
+            self.tx.try_send(request.encode_length_delimited_to_vec())
+        {
+            let spans: usize = request
+                .resource_spans
+                .iter()
+                .flat_map(|r| &r.scope_spans)
+                .map(|s| s.spans.len())
+                .sum();
+            let before = self.dropped_spans.fetch_add(spans, Ordering::Relaxed);
+            warn!(
+                "The recording lags behind, {} spans not recorded so far",
+                before + spans
+            );
+        }
+    }
+}
+
+/// Reads the next length-delimited message, `None` at the end of the input.
+fn read_delimited<R: Read>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut len: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let mut byte = [0u8];
+        if reader.read(&mut byte)? == 0 {
+            if shift == 0 {
+                return Ok(None);
+            }
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        len |= u64::from(byte[0] & 0x7f) << shift;
+        if byte[0] & 0x80 == 0 {
+            if len > MAX_MESSAGE_LEN {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "Message of {} bytes, above the {} maximum",
+                        len, MAX_MESSAGE_LEN
+                    ),
+                ));
+            }
+            let mut message = vec![0; len as usize];
+            reader.read_exact(&mut message)?;
+            return Ok(Some(message));
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "Invalid message length",
+    ))
+}
+
+/// The export requests of a recording, in the order they were received.
+pub struct RecordingReader<R> {
+    reader: R,
+}
+
+impl RecordingReader<BufReader<File>> {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("Unable to open the recording {}", path.display()))?;
+        Ok(Self {
+            reader: BufReader::new(file),
+        })
+    }
+}
+
+impl<R: Read> Iterator for RecordingReader<R> {
+    type Item = Result<ExportTraceServiceRequest>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match read_delimited(&mut self.reader) {
+            Ok(Some(message)) => {
+                Some(ExportTraceServiceRequest::decode(message.as_slice()).map_err(Into::into))
+            }
+            Ok(None) => None,
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
+
+/// When a request was exported, approximated by the latest end of its spans.
+fn export_time_nanos(request: &ExportTraceServiceRequest) -> Option<u64> {
+    request
+        .resource_spans
+        .iter()
+        .flat_map(|r| &r.scope_spans)
+        .flat_map(|s| &s.spans)
+        .map(|span| span.end_time_unix_nano)
+        .max()
+}
+
+/// How long to wait between two requests exported at `previous` and `next`,
+/// replayed `speed` times faster than recorded. A speed of 0 doesn't wait.
+fn replay_delay(previous: u64, next: u64, speed: f64) -> Duration {
+    if speed <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::from_nanos(next.saturating_sub(previous)).div_f64(speed)
+}
+
+/// Feeds a recording to the ingestor, at the pace the requests were
+/// originally exported, `speed` times faster.
+pub async fn replay(path: PathBuf, ingestor: TraceIngestor, speed: f64) -> Result<()> {
+    if !speed.is_finite() || speed < 0.0 {
+        bail!("Invalid replay speed {}", speed);
+    }
+    let mut previous = None;
+    let mut replayed = 0;
+    for request in RecordingReader::open(&path)? {
+        let request = request?;
+        if let Some(time) = export_time_nanos(&request) {
+            if let Some(previous) = previous {
+                tokio::time::sleep(replay_delay(previous, time, speed)).await;
+            }
+            previous = Some(previous.map_or(time, |p: u64| p.max(time)));
+        }
+
+        let spans = flatten_spans(request);
+        if !spans.is_empty() {
+            ingestor.ingest(spans).await?;
+        }
+        replayed += 1;
+    }
+    info!("Replayed {} requests from {}", replayed, path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_delimited() {
+        let mut input = vec![3, b'a', b'b', b'c', 0x80, 0x01];
+        input.extend([7; 128]);
+        let mut reader = input.as_slice();
+
+        assert_eq!(read_delimited(&mut reader).unwrap(), Some(b"abc".to_vec()));
+        assert_eq!(read_delimited(&mut reader).unwrap(), Some(vec![7; 128]));
+        assert_eq!(read_delimited(&mut reader).unwrap(), None);
+
+        let mut truncated: &[u8] = &[5, b'a'];
+        assert!(read_delimited(&mut truncated).is_err());
+
+        // 2^63, from a corrupt length
+        let mut corrupt: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01];
+        assert_eq!(
+            read_delimited(&mut corrupt).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_replay_delay() {
+        assert_eq!(replay_delay(1_000, 3_000, 1.0), Duration::from_nanos(2_000));
+        assert_eq!(replay_delay(1_000, 3_000, 4.0), Duration::from_nanos(500));
+        // Requests can arrive out of order
+        assert_eq!(replay_delay(3_000, 1_000, 1.0), Duration::ZERO);
+        assert_eq!(replay_delay(1_000, 3_000, 0.0), Duration::ZERO);
+    }
+}
@@ -1,40 +1,252 @@
-use crate::otel::{TraceGraphSnapshot, ingestor::TraceIngestor, trace_service::AmaruTraceService};
+use crate::{
+    config::OtelConfig,
+    metrics::{
+        model::MetricUpdate,
+        service::{self as metrics_service, AmaruMetricsService},
+    },
+    otel::{
+        TraceGraphSnapshot,
+        import::load_trace_file,
+        ingestor::TraceIngestor,
+        recording::{TraceRecorder, replay},
+        trace_service::{self, AmaruTraceService},
+    },
+};
 use anyhow::Result;
-use opentelemetry_proto::tonic::collector::trace::v1::trace_service_server::TraceServiceServer;
-use std::{net::SocketAddr, time::Duration};
-use tokio::task::{self, JoinHandle};
-use tonic::transport::Server;
+use opentelemetry_proto::tonic::collector::{
+    metrics::v1::metrics_service_server::MetricsServiceServer,
+    trace::v1::trace_service_server::TraceServiceServer,
+};
+use std::{
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tokio::{
+    net::TcpListener,
+    sync::mpsc::{self, Receiver, Sender},
+    task::{self, JoinHandle},
+};
+use tonic::transport::{Server, server::TcpIncoming};
+use tracing::{error, info};
 
+/// Where the collector gets its traces from, and what went wrong, shown on
+/// the traces page.
+#[derive(Clone, Debug, Default)]
+pub struct CollectorStatus {
+    pub source: String,
+    pub errors: Vec<String>,
+}
+
+pub type CollectorStatusHandle = Arc<RwLock<CollectorStatus>>;
+
+fn report_error(status: &CollectorStatusHandle, message: String) {
+    error!("{}", message);
+    status.write().unwrap().errors.push(message);
+}
+
+/// Collects amaru's OTLP exports, over gRPC and HTTP.
 pub struct OtelCollectorService {
-    addr: SocketAddr,
+    config: OtelConfig,
+    recorder: Option<TraceRecorder>,
+    status: CollectorStatusHandle,
 }
 
 pub struct OtelCollectorHandle {
     pub snapshot: TraceGraphSnapshot,
+    pub metrics: Receiver<MetricUpdate>,
+    pub status: CollectorStatusHandle,
     pub task_handle: JoinHandle<Result<()>>,
 }
 
+/// Serves the enabled OTLP/gRPC and OTLP/HTTP endpoints. Traces are only
+/// accepted when a trace service is given. A server failing to bind or
+/// stopping is reported in the status, without stopping the other one.
+async fn serve(
+    config: OtelConfig,
+    traces: Option<Arc<AmaruTraceService>>,
+    metrics: Sender<MetricUpdate>,
+    status: CollectorStatusHandle,
+) {
+    let metrics = Arc::new(metrics);
+    let grpc = async {
+        if !config.grpc {
+            return;
+        }
+        let incoming = match TcpIncoming::bind(config.grpc_addr) {
+            Ok(incoming) => incoming,
+            Err(e) => {
+                let message = format!("OTLP/gRPC can't listen on {}: {}", config.grpc_addr, e);
+                return report_error(&status, message);
+            }
+        };
+        info!("Listening for OTLP/gRPC on {}", config.grpc_addr);
+        let result = Server::builder()
+            .add_optional_service(traces.clone().map(TraceServiceServer::from_arc))
+            .add_service(MetricsServiceServer::new(AmaruMetricsService::new(
+                metrics.clone(),
+            )))
+            .serve_with_incoming(incoming)
+            .await;
+        if let Err(e) = result {
+            report_error(&status, format!("OTLP/gRPC server stopped: {}", e));
+        }
+    };
+    let http = async {
+        if !config.http {
+            return;
+        }
+        let listener = match TcpListener::bind(config.http_addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                let message = format!("OTLP/HTTP can't listen on {}: {}", config.http_addr, e);
+                return report_error(&status, message);
+            }
+        };
+        info!("Listening for OTLP/HTTP on {}", config.http_addr);
+        let mut router = metrics_service::routes(metrics.clone());
+        if let Some(traces) = &traces {
+            router = router.merge(trace_service::routes(traces.clone()));
+        }
+        if let Err(e) = axum::serve(listener, router).await {
+            report_error(&status, format!("OTLP/HTTP server stopped: {}", e));
+        }
+    };
+    tokio::join!(grpc, http);
+}
+
 impl OtelCollectorService {
-    pub fn new(addr: &str) -> Self {
+    pub fn new(config: OtelConfig) -> Self {
         Self {
-            addr: addr.parse().expect("Invalid address for OTEL service"),
+            config,
+            recorder: None,
+            status: CollectorStatusHandle::default(),
         }
     }
 
+    /// Records every request received, to be replayed later.
+    pub fn recorder(mut self, recorder: TraceRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    fn ingestor(&self, expire_duration: Duration) -> TraceIngestor {
+        TraceIngestor::new(
+            self.config.queue_capacity,
+            expire_duration,
+            self.config.max_spans,
+            self.config.max_memory_mib.saturating_mul(1024 * 1024),
+        )
+    }
+
+    fn set_source(&self, source: String) {
+        self.status.write().unwrap().source = source;
+    }
+
+    /// Listens for amaru's traces and metrics.
     pub fn start(self) -> OtelCollectorHandle {
-        let collector = TraceIngestor::new(10_000, Duration::from_secs(10 * 60));
+        let mut endpoints = Vec::new();
+        if self.config.grpc {
+            endpoints.push(format!("OTLP/gRPC {}", self.config.grpc_addr));
+        }
+        if self.config.http {
+            endpoints.push(format!("OTLP/HTTP {}", self.config.http_addr));
+        }
+        self.set_source(if endpoints.is_empty() {
+            "Not listening, OTLP/gRPC and OTLP/HTTP are disabled".to_string()
+        } else {
+            format!("Listening on {}", endpoints.join(", "))
+        });
+
+        let collector = self.ingestor(Duration::from_secs(self.config.retention_secs));
+        let snapshot = collector.snapshot();
+        let trace_service = Arc::new(AmaruTraceService::new(collector, self.recorder));
+        let (metrics_tx, metrics) = mpsc::channel(100);
+        let (config, status) = (self.config.clone(), self.status.clone());
+        let task_handle = task::spawn(async move {
+            serve(config, Some(trace_service), metrics_tx, status).await;
+            Ok(())
+        });
+
+        OtelCollectorHandle {
+            snapshot,
+            metrics,
+            status: self.status,
+            task_handle,
+        }
+    }
+
+    /// Listens for amaru's metrics only, traces coming from elsewhere.
+    fn start_metrics(&self) -> Receiver<MetricUpdate> {
+        let (metrics_tx, metrics) = mpsc::channel(100);
+        task::spawn(serve(
+            self.config.clone(),
+            None,
+            metrics_tx,
+            self.status.clone(),
+        ));
+        metrics
+    }
+
+    /// Feeds a recording to the trace graph instead of listening for amaru's
+    /// traces. Replayed traces are never evicted by age, they were recorded
+    /// to be analysed.
+    pub fn replay(self, path: PathBuf, speed: f64) -> OtelCollectorHandle {
+        let metrics = self.start_metrics();
+        let collector = self.ingestor(Duration::MAX);
         let snapshot = collector.snapshot();
-        let trace_service = AmaruTraceService::new(collector);
+        self.set_source(format!("Replaying {}", path.display()));
+        let status = self.status.clone();
+        let task_handle = task::spawn(async move {
+            let result = replay(path, collector, speed).await;
+            if let Err(e) = &result {
+                report_error(&status, format!("Failed to replay the recording: {}", e));
+            }
+            result
+        });
+
+        OtelCollectorHandle {
+            snapshot,
+            metrics,
+            status: self.status,
+            task_handle,
+        }
+    }
+
+    /// Loads trace files exported by other tools, such as the dumps of CI
+    /// runs, instead of listening for amaru's traces. Like replays, they are
+    /// never evicted by age.
+    pub fn open(self, paths: Vec<PathBuf>) -> OtelCollectorHandle {
+        let metrics = self.start_metrics();
+        let collector = self.ingestor(Duration::MAX);
+        let snapshot = collector.snapshot();
+        self.set_source(match paths.as_slice() {
+            [path] => format!("Opened {}", path.display()),
+            _ => format!("Opened {} trace files", paths.len()),
+        });
+        let status = self.status.clone();
         let task_handle = task::spawn(async move {
-            Server::builder()
-                .add_service(TraceServiceServer::new(trace_service))
-                .serve(self.addr)
+            for path in paths {
+                let spans = task::spawn_blocking({
+                    let path = path.clone();
+                    move || load_trace_file(&path)
+                })
                 .await?;
+                match spans {
+                    Ok(spans) => {
+                        info!("Loaded {} spans from {}", spans.len(), path.display());
+                        collector.ingest(spans).await?;
+                    }
+                    Err(e) => report_error(&status, format!("{:#}", e)),
+                }
+            }
             Ok(())
         });
 
         OtelCollectorHandle {
             snapshot,
+            metrics,
+            status: self.status,
             task_handle,
         }
     }
@@ -13,11 +13,11 @@ pub struct TraceStore {
 }
 
 impl TraceStore {
-    pub fn new(expire_duration: Duration) -> Self {
+    pub fn new(expire_duration: Duration, max_spans: usize, max_bytes: usize) -> Self {
         Self {
             graph: TraceGraph::default(),
             orphanage: Orphanage::default(),
-            evictor: Evictor::new(expire_duration),
+            evictor: Evictor::new(expire_duration, max_spans, max_bytes),
         }
     }
 
@@ -66,7 +66,8 @@ impl TraceStore {
         }
     }
 
-    /// Evicts all expired traces from the graph and expired orphans.
+    /// Evicts all expired traces from the graph and expired orphans, then the
+    /// oldest traces while the graph holds too many spans or bytes.
     /// Returns the list of TraceIds if any traces were evicted from the main
     /// graph.
     pub fn evict_expired(&mut self) -> Vec<TraceId> {
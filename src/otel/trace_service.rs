@@ -1,21 +1,60 @@
-use crate::otel::ingestor::TraceIngestor;
-use opentelemetry_proto::tonic::collector::trace::v1::{
-    ExportTraceServiceRequest, ExportTraceServiceResponse, trace_service_server::TraceService,
+use crate::otel::{http::decode_request, ingestor::TraceIngestor, recording::TraceRecorder};
+use axum::{
+    Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response as HttpResponse},
+    routing::post,
 };
+use bytes::Bytes;
+use opentelemetry_proto::tonic::{
+    collector::trace::v1::{
+        ExportTraceServiceRequest, ExportTraceServiceResponse, trace_service_server::TraceService,
+    },
+    trace::v1::Span,
+};
+use std::sync::Arc;
+use tokio::sync::mpsc::error::SendError;
 use tonic::{Request, Response, Status};
 
 pub struct AmaruTraceService {
     ingestor: TraceIngestor,
+    recorder: Option<TraceRecorder>,
 }
 
 impl AmaruTraceService {
-    pub fn new(collector: TraceIngestor) -> Self {
+    pub fn new(collector: TraceIngestor, recorder: Option<TraceRecorder>) -> Self {
         Self {
             ingestor: collector,
+            recorder,
+        }
+    }
+
+    /// Records and ingests a request, whichever protocol it was received on.
+    async fn accept(&self, request: ExportTraceServiceRequest) -> Result<(), SendError<Vec<Span>>> {
+        if let Some(recorder) = &self.recorder {
+            recorder.record(&request);
         }
+
+        let all_spans = flatten_spans(request);
+        if !all_spans.is_empty() {
+            self.ingestor.ingest(all_spans).await?;
+        }
+        Ok(())
     }
 }
 
+/// Flattens the spans of a request into a single Vec.
+pub fn flatten_spans(request: ExportTraceServiceRequest) -> Vec<Span> {
+    let mut all_spans = Vec::new();
+    for r_spans in request.resource_spans {
+        for s_spans in r_spans.scope_spans {
+            all_spans.extend(s_spans.spans);
+        }
+    }
+    all_spans
+}
+
 /// The main entry-point for accepting amaru OTEL data.
 #[tonic::async_trait]
 impl TraceService for AmaruTraceService {
@@ -23,21 +62,75 @@ impl TraceService for AmaruTraceService {
         &self,
         req: Request<ExportTraceServiceRequest>,
     ) -> Result<Response<ExportTraceServiceResponse>, Status> {
-        // Flatten spans into a single Vec
-        let mut all_spans = Vec::new();
-        for r_spans in req.into_inner().resource_spans {
-            for s_spans in r_spans.scope_spans {
-                all_spans.extend(s_spans.spans);
-            }
-        }
-
-        if !all_spans.is_empty() {
-            self.ingestor
-                .ingest(all_spans)
-                .await
-                .map_err(|e| Status::from_error(Box::new(e)))?;
-        }
+        self.accept(req.into_inner())
+            .await
+            .map_err(|e| Status::from_error(Box::new(e)))?;
 
         Ok(Response::new(ExportTraceServiceResponse::default()))
     }
 }
+
+async fn handle_traces(
+    State(service): State<Arc<AmaruTraceService>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> HttpResponse {
+    let (request, encoding) = match decode_request(&headers, body).await {
+        Ok(decoded) => decoded,
+        Err(status) => return status.into_response(),
+    };
+    match service.accept(request).await {
+        Ok(()) => encoding.ok(),
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    }
+}
+
+/// The OTLP/HTTP endpoint for traces, sharing the service with gRPC.
+pub fn routes(service: Arc<AmaruTraceService>) -> Router {
+    Router::new()
+        .route("/v1/traces", post(handle_traces))
+        .with_state(service)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{HeaderValue, header};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_handle_traces() {
+        let ingestor = TraceIngestor::new(10, Duration::MAX, 1_000, 1 << 20);
+        let snapshot = ingestor.snapshot();
+        let service = Arc::new(AmaruTraceService::new(ingestor, None));
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        let body = Bytes::from_static(
+            br#"{"resourceSpans":[{"scopeSpans":[{"spans":[{
+            "traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174",
+            "name":"roll_forward","startTimeUnixNano":"1000","endTimeUnixNano":"3000"}]}]}]}"#,
+        );
+
+        let response = handle_traces(State(service.clone()), headers.clone(), body).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+
+        let response = handle_traces(State(service), headers, Bytes::from_static(b"[")).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // The processor publishes its graph periodically
+        for _ in 0..40 {
+            if !snapshot.load().spans.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(snapshot.load().spans.len(), 1);
+    }
+}
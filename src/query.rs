@@ -0,0 +1,64 @@
+use crate::{
+    cli::{LedgerQuery, OutputFormat},
+    store::owned_iter::{OwnedAccountIter, OwnedPoolIter},
+    ui::to_json::ToJson,
+};
+use amaru_kernel::{Address, Hash, StakeCredential, to_cbor};
+use amaru_ledger::store::ReadStore;
+use amaru_stores::rocksdb::ReadOnlyRocksDB;
+use anyhow::{Result, anyhow};
+use std::{
+    io::{self, Write},
+    str::FromStr,
+    sync::Arc,
+};
+
+/// Runs a headless ledger query, printing every matching entry to stdout.
+pub fn run(db: Arc<ReadOnlyRocksDB>, query: &LedgerQuery, format: OutputFormat) -> Result<()> {
+    let mut out = io::stdout().lock();
+    match query {
+        LedgerQuery::Account { credential } => {
+            let hash = Hash::<28>::from_str(credential)
+                .map_err(|e| anyhow!("Invalid stake credential {}: {:?}", credential, e))?;
+            let account = OwnedAccountIter::new(db).find(|(key, _)| match key {
+                StakeCredential::AddrKeyhash(h) | StakeCredential::ScriptHash(h) => *h == hash,
+            });
+            let account = account.ok_or_else(|| anyhow!("No account for {}", credential))?;
+            print_item(&mut out, &account, format)
+        }
+        LedgerQuery::Pool { pool_id } => {
+            let id = Hash::<28>::from_str(pool_id)
+                .map_err(|e| anyhow!("Invalid pool id {}: {:?}", pool_id, e))?;
+            let pool = OwnedPoolIter::new(db).find(|(key, _)| *key == id);
+            let pool = pool.ok_or_else(|| anyhow!("No pool for {}", pool_id))?;
+            print_item(&mut out, &pool, format)
+        }
+        LedgerQuery::Utxos { address } => {
+            let address = Address::from_str(address)
+                .map_err(|e| anyhow!("Invalid address {}: {:?}", address, e))?;
+            let utxos = ReadStore::iter_utxos(&*db)?.filter(|(_, out)| out.address == address);
+            for utxo in utxos {
+                print_item(&mut out, &utxo, format)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Prints a single item, JSON items are printed one per line so the output can
+/// be consumed as JSON lines.
+fn print_item<T, W>(out: &mut W, item: &T, format: OutputFormat) -> Result<()>
+where
+    T: ToJson + minicbor::Encode<()>,
+    W: Write,
+{
+    match format {
+        OutputFormat::Json => writeln!(out, "{}", item.to_json())?,
+        OutputFormat::CborDiag => {
+            let diag = cbor_diag::parse_bytes(to_cbor(item))
+                .map_err(|e| anyhow!("Unable to parse CBOR: {}", e))?;
+            writeln!(out, "{}", diag.to_diag_pretty())?
+        }
+    }
+    Ok(())
+}
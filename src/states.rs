@@ -36,17 +36,44 @@ pub enum ComponentId {
     LedgerUtxoDetails,
     LedgerUtxosByAddrList,
     LedgerUtxosByAddrDetails,
+    LedgerUtxoByInputList,
+    LedgerUtxoByInputDetails,
+    LedgerUtxosByStakeList,
+    LedgerUtxosByStakeDetails,
+    LedgerUtxosByPaymentList,
+    LedgerUtxosByPaymentDetails,
+    LedgerUtxosByAssetList,
+    LedgerUtxosByAssetDetails,
+    LedgerUtxoTotals,
+    LedgerProtocolParamsList,
+    LedgerProtocolParamDetails,
+    LedgerEpochStateList,
+    LedgerEpochStateDetails,
+    LedgerGovernanceList,
+    LedgerGovernanceDetails,
+    LedgerCommitteeList,
+    LedgerCommitteeDetails,
+    LedgerStakeList,
+    LedgerStakeDetails,
+    LedgerBlockProductionList,
+    LedgerBlockProductionDetails,
+    LedgerDiffList,
+    LedgerDiffDetails,
+    LedgerRefreshStatus,
 
     // --- Chain Page ---
     ChainSearch,
     ChainSearchHeader,
     ChainSearchBlock,
     ChainSearchNonces,
+    ChainForkTree,
 
     // --- Otel Page ---
     OtelTraceList,
     OtelFlameGraph,
     OtelSpanDetails,
+    OtelCollectorStatus,
+    OtelStoreStats,
 
     // --- Metrics Page ---
     Metrics,
@@ -78,6 +105,8 @@ pub enum Action {
     SetFocus(ComponentId),
     FocusNext,
     FocusPrev,
+    Export(ExportFormat),
+    CopyToClipboard(String),
 }
 
 impl Action {
@@ -86,6 +115,15 @@ impl Action {
     }
 }
 
+/// The file formats the focused list or details can be exported to.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Cbor,
+}
+
 #[derive(Clone, Debug, Default, Display, EnumIter, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum LedgerBrowse {
@@ -93,10 +131,20 @@ pub enum LedgerBrowse {
     Accounts,
     #[serde(rename = "block issuers")]
     BlockIssuers,
+    #[serde(rename = "block production")]
+    BlockProduction,
+    Committee,
     DReps,
     Pools,
     Proposals,
     Utxos,
+    #[serde(rename = "protocol parameters")]
+    ProtocolParameters,
+    #[serde(rename = "epoch state")]
+    EpochState,
+    Governance,
+    #[serde(rename = "stake distribution")]
+    StakeDistribution,
 }
 
 impl ToListItem for LedgerBrowse {
@@ -111,6 +159,7 @@ pub enum LedgerMode {
     #[default]
     Browse,
     Search,
+    Diff,
 }
 
 #[derive(Clone, Copy, Default, Debug, EnumIter, PartialEq, Eq, Serialize)]
@@ -118,6 +167,14 @@ pub enum LedgerSearch {
     #[default]
     #[serde(rename = "utxos by address")]
     UtxosByAddress,
+    #[serde(rename = "utxo by input")]
+    UtxoByInput,
+    #[serde(rename = "utxos by stake credential")]
+    UtxosByStakeCredential,
+    #[serde(rename = "utxos by payment credential")]
+    UtxosByPaymentCredential,
+    #[serde(rename = "utxos by asset")]
+    UtxosByAsset,
 }
 
 impl ToListItem for LedgerSearch {
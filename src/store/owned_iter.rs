@@ -1,5 +1,5 @@
 use crate::ui::to_list_item::{
-    AccountItem, BlockIssuerItem, DRepItem, PoolItem, ProposalItem, UtxoItem,
+    AccountItem, BlockIssuerItem, CommitteeMemberItem, DRepItem, PoolItem, ProposalItem, UtxoItem,
 };
 use amaru_ledger::store::ReadStore;
 use amaru_stores::rocksdb::ReadOnlyRocksDB;
@@ -70,6 +70,11 @@ macro_rules! define_owned_db_iter {
 
 define_owned_db_iter!(OwnedAccountIter, AccountItem, iter_accounts);
 define_owned_db_iter!(OwnedBlockIssuerIter, BlockIssuerItem, iter_block_issuers);
+define_owned_db_iter!(
+    OwnedCommitteeMemberIter,
+    CommitteeMemberItem,
+    iter_cc_members
+);
 define_owned_db_iter!(OwnedDRepIter, DRepItem, iter_dreps);
 define_owned_db_iter!(OwnedPoolIter, PoolItem, iter_pools);
 define_owned_db_iter!(OwnedProposalIter, ProposalItem, iter_proposals);
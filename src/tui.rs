@@ -1,5 +1,6 @@
 use anyhow::Result;
 use crossterm::{
+    clipboard::CopyToClipboard,
     cursor,
     event::{
         DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
@@ -167,6 +168,13 @@ impl<B: Backend> Tui<B> {
         Ok(())
     }
 
+    /// Copies the text to the clipboard of the terminal with an OSC 52 escape
+    /// sequence, which also works over SSH.
+    pub fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+        crossterm::execute!(stdout(), CopyToClipboard::to_clipboard_from(text))?;
+        Ok(())
+    }
+
     pub fn cancel(&self) {
         self.cancellation_token.cancel();
     }
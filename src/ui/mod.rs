@@ -1,11 +1,14 @@
+use crate::model::ledger_diff::DiffKind;
 use amaru_kernel::{Bytes, CertificatePointer, Hash, KeyValuePairs, Nullable, RationalNumber, Set};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use std::fmt;
 use std::time::Duration;
 
+pub mod to_json;
 pub mod to_list_item;
 pub mod to_rich;
+pub mod to_table_row;
 
 const LABEL_STYLE: Style = Style::new().fg(Color::Gray).add_modifier(Modifier::BOLD);
 
@@ -243,3 +246,39 @@ pub fn format_duration(duration: Duration) -> String {
         format!("{} µs", micros)
     }
 }
+
+/// The style of an entry that was removed, added or changed.
+pub fn diff_style(kind: DiffKind) -> Style {
+    match kind {
+        DiffKind::Removed => Style::default()
+            .fg(Color::Red)
+            .add_modifier(Modifier::CROSSED_OUT),
+        DiffKind::Added => Style::default().fg(Color::Green),
+        DiffKind::Changed => Style::default().fg(Color::Yellow),
+    }
+}
+
+/// Renders values as a line of block characters, scaled to the largest one.
+/// Zero values are drawn as spaces so gaps stand out.
+pub fn sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or_default().max(1);
+    values
+        .iter()
+        .map(|value| match value {
+            0 => ' ',
+            value => BARS[((value * 8 - 1) / max).min(7) as usize],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[0, 1, 4, 8]), " ▁▄█");
+    }
+}
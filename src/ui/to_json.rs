@@ -0,0 +1,242 @@
+use crate::{
+    model::{ledger_diff::DiffEntry, proposal::ProposalEntry},
+    ui::{
+        RationalNumberDisplay,
+        to_list_item::{
+            AccountItem, BlockIssuerItem, CommitteeMemberItem, DRepItem, PoolItem, ProposalItem,
+            UtxoItem,
+        },
+        to_rich::{
+            account::StakeCredentialDisplay,
+            proposal::{ComparableProposalIdDisplay, gov_action_type},
+            utxo::TransactionInputDisplay,
+        },
+    },
+};
+use amaru_kernel::{
+    DRep, MemoizedDatum, MemoizedScript, Nullable, PoolParams, PseudoScript, StakeCredential, Value,
+};
+use serde_json::{Value as Json, json};
+
+/// A machine-readable representation of an item, used by the headless
+/// commands and exports.
+pub trait ToJson {
+    fn to_json(&self) -> Json;
+}
+
+fn credential_kind(credential: &StakeCredential) -> &'static str {
+    match credential {
+        StakeCredential::AddrKeyhash(_) => "key",
+        StakeCredential::ScriptHash(_) => "script",
+    }
+}
+
+fn drep_to_json(drep: &DRep) -> Json {
+    match drep {
+        DRep::Key(h) => json!({ "key": h.to_string() }),
+        DRep::Script(h) => json!({ "script": h.to_string() }),
+        DRep::Abstain => json!("abstain"),
+        DRep::NoConfidence => json!("no_confidence"),
+    }
+}
+
+fn value_to_json(value: &Value) -> Json {
+    match value {
+        Value::Coin(c) => json!({ "lovelace": c, "assets": [] }),
+        Value::Multiasset(coin, assets) => {
+            let assets: Vec<Json> = assets
+                .iter()
+                .flat_map(|(policy, aset)| {
+                    aset.iter().map(move |(name, amount)| {
+                        json!({
+                            "policy": policy.to_string(),
+                            "name": name.to_string(),
+                            "quantity": u64::from(*amount),
+                        })
+                    })
+                })
+                .collect();
+            json!({ "lovelace": coin, "assets": assets })
+        }
+    }
+}
+
+fn datum_to_json(datum: &MemoizedDatum) -> Json {
+    match datum {
+        MemoizedDatum::None => Json::Null,
+        MemoizedDatum::Hash(h) => json!({ "hash": hex::encode(h) }),
+        MemoizedDatum::Inline(d) => json!({ "inline": hex::encode(d.original_bytes()) }),
+    }
+}
+
+fn script_to_json(script: &MemoizedScript) -> Json {
+    match script {
+        PseudoScript::NativeScript(s) => json!({ "native": hex::encode(s.original_bytes()) }),
+        PseudoScript::PlutusV1Script(s) => json!({ "plutus_v1": hex::encode(&*s.0) }),
+        PseudoScript::PlutusV2Script(s) => json!({ "plutus_v2": hex::encode(&*s.0) }),
+        PseudoScript::PlutusV3Script(s) => json!({ "plutus_v3": hex::encode(&*s.0) }),
+    }
+}
+
+impl ToJson for PoolParams {
+    fn to_json(&self) -> Json {
+        json!({
+            "vrf": self.vrf.to_string(),
+            "pledge": self.pledge,
+            "cost": self.cost,
+            "margin": RationalNumberDisplay(&self.margin).to_string(),
+            "reward_account": self.reward_account.to_string(),
+            "owners": self.owners.iter().map(|o| o.to_string()).collect::<Vec<_>>(),
+            "relays": self.relays.iter().map(|r| format!("{:?}", r)).collect::<Vec<_>>(),
+            "metadata": match &self.metadata {
+                Nullable::Some(m) => json!({ "url": m.url, "hash": m.hash.to_string() }),
+                _ => Json::Null,
+            },
+        })
+    }
+}
+
+impl ToJson for AccountItem {
+    fn to_json(&self) -> Json {
+        let (credential, row) = self;
+        json!({
+            "credential": StakeCredentialDisplay(credential).to_string(),
+            "credential_type": credential_kind(credential),
+            "pool": row.pool.map(|(pool_id, _)| pool_id.to_string()),
+            "drep": row.drep.as_ref().map(|(drep, _)| drep_to_json(drep)),
+            "deposit": row.deposit,
+            "rewards": row.rewards,
+        })
+    }
+}
+
+impl ToJson for PoolItem {
+    fn to_json(&self) -> Json {
+        let (id, row) = self;
+        let future_params: Vec<Json> = row
+            .future_params
+            .iter()
+            .map(|(params, epoch)| {
+                json!({
+                    "epoch": epoch.to_string(),
+                    "params": params.as_ref().map(ToJson::to_json),
+                    "retirement": params.is_none(),
+                })
+            })
+            .collect();
+        json!({
+            "id": id.to_string(),
+            "current_params": row.current_params.to_json(),
+            "future_params": future_params,
+        })
+    }
+}
+
+impl ToJson for UtxoItem {
+    fn to_json(&self) -> Json {
+        let (input, output) = self;
+        json!({
+            "input": TransactionInputDisplay(input).to_string(),
+            "address": output.address.to_string(),
+            "value": value_to_json(&output.value),
+            "datum": datum_to_json(&output.datum),
+            "script": output.script.as_ref().map(script_to_json),
+        })
+    }
+}
+
+impl ToJson for BlockIssuerItem {
+    fn to_json(&self) -> Json {
+        let (slot, row) = self;
+        json!({
+            "slot": slot.to_string(),
+            "slot_leader": row.slot_leader.to_string(),
+        })
+    }
+}
+
+impl ToJson for CommitteeMemberItem {
+    fn to_json(&self) -> Json {
+        let (cold, row) = self;
+        json!({
+            "cold_credential": StakeCredentialDisplay(cold).to_string(),
+            "cold_credential_type": credential_kind(cold),
+            "hot_credential": row.hot_credential.as_ref().map(|hot| {
+                json!({
+                    "credential": StakeCredentialDisplay(hot).to_string(),
+                    "credential_type": credential_kind(hot),
+                })
+            }),
+            "expires_at": row.expires_at.map(|epoch| epoch.to_string()),
+        })
+    }
+}
+
+impl ToJson for DRepItem {
+    fn to_json(&self) -> Json {
+        let (credential, row) = self;
+        json!({
+            "credential": StakeCredentialDisplay(credential).to_string(),
+            "credential_type": credential_kind(credential),
+            "deposit": row.deposit,
+            "anchor": row.anchor.as_ref().map(|anchor| {
+                json!({ "url": anchor.url, "content_hash": anchor.content_hash.to_string() })
+            }),
+            "valid_until": row.valid_until.to_string(),
+        })
+    }
+}
+
+impl ToJson for ProposalItem {
+    fn to_json(&self) -> Json {
+        let (id, row) = self;
+        let pointer = &row.proposed_in;
+        json!({
+            "id": ComparableProposalIdDisplay(id).to_string(),
+            "type": gov_action_type(&row.proposal.gov_action),
+            "proposed_in": {
+                "slot": pointer.transaction.slot.to_string(),
+                "transaction_index": pointer.transaction.transaction_index,
+                "proposal_index": pointer.proposal_index,
+            },
+            "valid_until": row.valid_until.to_string(),
+            "deposit": row.proposal.deposit,
+            "reward_account": row.proposal.reward_account.to_string(),
+            "anchor": {
+                "url": row.proposal.anchor.url,
+                "content_hash": row.proposal.anchor.content_hash.to_string(),
+            },
+        })
+    }
+}
+
+impl ToJson for ProposalEntry {
+    fn to_json(&self) -> Json {
+        let mut json = self.item.to_json();
+        if let Some(object) = json.as_object_mut() {
+            object.insert(
+                "epoch".to_string(),
+                json!(self.epoch.map(|epoch| epoch.to_string())),
+            );
+            object.insert(
+                "status".to_string(),
+                json!(self.status.map(|status| status.to_string())),
+            );
+        }
+        json
+    }
+}
+
+impl ToJson for DiffEntry {
+    fn to_json(&self) -> Json {
+        match self {
+            Self::Account(item) => item.to_json(),
+            Self::Pool(item) => item.to_json(),
+            Self::DRep(item) => item.to_json(),
+            Self::Proposal(item) => item.to_json(),
+            Self::BlockIssuer(item) => item.to_json(),
+            Self::CommitteeMember(item) => item.to_json(),
+            Self::Utxo(item) => item.to_json(),
+        }
+    }
+}
@@ -1,8 +1,20 @@
-use amaru_ledger::store::columns::{accounts, dreps, pools, proposals, slots, utxo};
-use ratatui::widgets::ListItem;
+use amaru_ledger::store::columns::{accounts, cc_members, dreps, pools, proposals, slots, utxo};
+use ratatui::{
+    style::{Color, Style},
+    widgets::ListItem,
+};
 
 use crate::{
+    model::{
+        block_production::PoolBlocks,
+        governance::ProposalTally,
+        ledger_diff::{DiffKind, LedgerDifference},
+        ledger_state::LedgerStateField,
+        proposal::ProposalEntry,
+        stake::PoolStake,
+    },
     otel::id::TraceId,
+    ui::sparkline,
     ui::to_rich::{
         account::StakeCredentialDisplay, proposal::ComparableProposalIdDisplay,
         utxo::TransactionInputDisplay,
@@ -29,6 +41,14 @@ impl ToListItem for BlockIssuerItem {
     }
 }
 
+pub type CommitteeMemberItem = (cc_members::Key, cc_members::Row);
+
+impl ToListItem for CommitteeMemberItem {
+    fn to_list_item(&self) -> ListItem<'static> {
+        ListItem::new(StakeCredentialDisplay(&self.0).to_string())
+    }
+}
+
 pub type DRepItem = (dreps::Key, dreps::Row);
 
 impl ToListItem for DRepItem {
@@ -53,6 +73,12 @@ impl ToListItem for ProposalItem {
     }
 }
 
+impl ToListItem for ProposalEntry {
+    fn to_list_item(&self) -> ListItem<'static> {
+        self.item.to_list_item()
+    }
+}
+
 pub type UtxoItem = (utxo::Key, utxo::Value);
 
 impl ToListItem for UtxoItem {
@@ -61,6 +87,73 @@ impl ToListItem for UtxoItem {
     }
 }
 
+impl ToListItem for LedgerStateField {
+    fn to_list_item(&self) -> ListItem<'static> {
+        let item = ListItem::new(self.name.clone());
+        if self.changed() {
+            item.style(Style::default().fg(Color::Yellow))
+        } else {
+            item
+        }
+    }
+}
+
+impl ToListItem for ProposalTally {
+    fn to_list_item(&self) -> ListItem<'static> {
+        let item = ListItem::new(ComparableProposalIdDisplay(self.id()).to_string());
+        if self.expired {
+            item.style(Style::default().fg(Color::DarkGray))
+        } else if self.thresholds_met() {
+            item.style(Style::default().fg(Color::Green))
+        } else {
+            item
+        }
+    }
+}
+
+impl ToListItem for PoolStake {
+    fn to_list_item(&self) -> ListItem<'static> {
+        let item = ListItem::new(format!(
+            "#{} {} {:.1}%",
+            self.rank,
+            self.pool_id,
+            self.saturation * 100.0
+        ));
+        if self.saturation > 1.0 {
+            item.style(Style::default().fg(Color::Red))
+        } else {
+            item
+        }
+    }
+}
+
+/// How many of the latest epochs the block production sparklines cover.
+const SPARKLINE_EPOCHS: usize = 24;
+
+impl ToListItem for PoolBlocks {
+    fn to_list_item(&self) -> ListItem<'static> {
+        let recent = &self.per_epoch[self.per_epoch.len().saturating_sub(SPARKLINE_EPOCHS)..];
+        ListItem::new(format!(
+            "{} {:>6} {}",
+            self.pool_id,
+            self.total(),
+            sparkline(recent)
+        ))
+    }
+}
+
+impl ToListItem for LedgerDifference {
+    fn to_list_item(&self) -> ListItem<'static> {
+        let (sign, color) = match self.kind() {
+            DiffKind::Removed => ('-', Color::Red),
+            DiffKind::Added => ('+', Color::Green),
+            DiffKind::Changed => ('~', Color::Yellow),
+        };
+        ListItem::new(format!("{} {} {}", sign, self.column, self.identifier()))
+            .style(Style::default().fg(color))
+    }
+}
+
 impl ToListItem for TraceId {
     fn to_list_item(&self) -> ListItem<'static> {
         self.to_string().into()
@@ -1,9 +1,15 @@
-use crate::ui::{RichText, ToRichText, labeled, labeled_default_single, to_list_item::AccountItem};
-use amaru_kernel::{DRep, StakeCredential};
+use crate::{
+    model::account_details::AccountDetails,
+    ui::{
+        RationalNumberDisplay, RichText, ToRichText, labeled, labeled_default_single,
+        to_list_item::AccountItem,
+    },
+};
+use amaru_kernel::{DRep, Nullable, StakeCredential};
 use amaru_ledger::store::columns::accounts::Row;
 use ratatui::{
     style::{Color, Style},
-    text::Span,
+    text::{Line, Span},
 };
 use std::fmt;
 
@@ -98,3 +104,68 @@ impl ToRichText for DRep {
         ))
     }
 }
+
+impl ToRichText for AccountDetails {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = self.account.to_rich_text().unwrap_lines();
+
+        lines.push(Line::from(""));
+        match &self.pool {
+            None => lines.extend(labeled_default_single("Delegated Pool", "None")),
+            Some((pool_id, pool_row)) => {
+                lines.extend(labeled_default_single("Delegated Pool", pool_id));
+                match pool_row {
+                    None => lines.extend(labeled(
+                        "Pool Status".to_string(),
+                        RichText::Single(Span::raw("Not registered")),
+                        Style::default().fg(Color::Red),
+                    )),
+                    Some(row) => {
+                        let params = &row.current_params;
+                        let metadata_url = match &params.metadata {
+                            Nullable::Some(metadata) => metadata.url.clone(),
+                            _ => "None".to_string(),
+                        };
+                        lines.extend(labeled_default_single("Pool Metadata URL", metadata_url));
+                        lines.extend(labeled_default_single(
+                            "Pool Margin",
+                            RationalNumberDisplay(&params.margin),
+                        ));
+                        let retirement = row
+                            .future_params
+                            .iter()
+                            .find(|(params, _)| params.is_none())
+                            .map(|(_, epoch)| epoch);
+                        lines.extend(match retirement {
+                            Some(epoch) => labeled(
+                                "Pool Status".to_string(),
+                                RichText::Single(Span::raw(format!("Retiring at epoch {}", epoch))),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                            None => labeled(
+                                "Pool Status".to_string(),
+                                RichText::Single(Span::raw("Active")),
+                                Style::default().fg(Color::Green),
+                            ),
+                        });
+                    }
+                }
+                lines.extend(labeled_default_single("Jump to Pool", "press p"));
+            }
+        }
+
+        lines.push(Line::from(""));
+        match &self.drep {
+            None => lines.extend(labeled_default_single("Delegated DRep", "None")),
+            Some((drep, drep_row)) => {
+                lines.extend(drep.to_rich_text().unwrap_lines());
+                if let Some(row) = drep_row {
+                    lines.extend(row.to_rich_text().unwrap_lines());
+                    lines.extend(labeled_default_single("Jump to DRep", "press d"));
+                }
+            }
+        }
+
+        RichText::Lines(lines)
+    }
+}
@@ -1,7 +1,11 @@
-use crate::ui::{
-    RichText, ToRichText, labeled_default_single, to_list_item::BlockIssuerItem,
-    to_rich::pool::PoolIdDisplay,
+use crate::{
+    model::block_production::PoolBlocks,
+    ui::{
+        RichText, ToRichText, labeled_default_single, sparkline, to_list_item::BlockIssuerItem,
+        to_rich::pool::PoolIdDisplay,
+    },
 };
+use ratatui::text::Line;
 
 impl ToRichText for BlockIssuerItem {
     fn to_rich_text(&self) -> RichText {
@@ -14,3 +18,34 @@ impl ToRichText for BlockIssuerItem {
         RichText::Lines(lines)
     }
 }
+
+impl ToRichText for PoolBlocks {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single(
+            "Pool",
+            PoolIdDisplay(self.pool_id).to_string(),
+        ));
+        lines.extend(labeled_default_single("Blocks", self.total()));
+        lines.extend(labeled_default_single(
+            "Epochs",
+            format!(
+                "{}..{}",
+                self.first_epoch,
+                self.first_epoch + self.per_epoch.len().saturating_sub(1) as u64
+            ),
+        ));
+        lines.push(Line::raw(sparkline(&self.per_epoch)));
+        lines.extend(labeled_default_single("Blocks per Epoch", ""));
+        for (epoch, count) in (self.first_epoch..).zip(&self.per_epoch) {
+            if *count > 0 {
+                lines.push(Line::raw(format!("  {}: {}", epoch, count)));
+            }
+        }
+        lines.extend(labeled_default_single("Slots", ""));
+        for slot in &self.slots {
+            lines.push(Line::raw(format!("  {}", slot)));
+        }
+        RichText::Lines(lines)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::ui::{
+    RichText, ToRichText, labeled, labeled_default_opt_single, labeled_default_single,
+    to_list_item::CommitteeMemberItem, to_rich::account::StakeCredentialDisplay,
+};
+use amaru_ledger::store::columns::cc_members;
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+impl ToRichText for CommitteeMemberItem {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single(
+            "Cold Credential",
+            StakeCredentialDisplay(&self.0),
+        ));
+        lines.extend(self.1.to_rich_text().unwrap_lines());
+        RichText::Lines(lines)
+    }
+}
+
+impl ToRichText for cc_members::Row {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(match &self.hot_credential {
+            Some(hot) => labeled_default_single("Hot Credential", StakeCredentialDisplay(hot)),
+            // A member without a hot key either resigned or never authorized one
+            None => labeled(
+                "Hot Credential".to_string(),
+                RichText::Single(Span::raw("None (resigned or not authorized)")),
+                Style::default().fg(Color::Red),
+            ),
+        });
+        lines.extend(labeled_default_opt_single("Expires At", self.expires_at));
+        RichText::Lines(lines)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    model::governance::{ProposalTally, Tally},
+    ui::{
+        RationalNumberDisplay, RichText, ToRichText, labeled, labeled_default_single,
+        to_rich::proposal::{ComparableProposalIdDisplay, gov_action_type},
+    },
+};
+use amaru_kernel::{RationalNumber, Vote, Voter};
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use std::fmt;
+
+pub struct VoterDisplay<'a>(pub &'a Voter);
+
+impl<'a> fmt::Display for VoterDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Voter::ConstitutionalCommitteeKey(hash) => write!(f, "CC Key({})", hash),
+            Voter::ConstitutionalCommitteeScript(hash) => write!(f, "CC Script({})", hash),
+            Voter::DRepKey(hash) => write!(f, "DRep Key({})", hash),
+            Voter::DRepScript(hash) => write!(f, "DRep Script({})", hash),
+            Voter::StakePoolKey(hash) => write!(f, "SPO({})", hash),
+        }
+    }
+}
+
+fn vote_style(vote: &Vote) -> Style {
+    match vote {
+        Vote::Yes => Style::default().fg(Color::Green),
+        Vote::No => Style::default().fg(Color::Red),
+        Vote::Abstain => Style::default().fg(Color::Gray),
+    }
+}
+
+/// The tally of a stake weighted group, along with its yes ratio and how it
+/// compares to the threshold.
+fn stake_tally_text(
+    tally: &Tally,
+    ratio: Option<f64>,
+    threshold: Option<&RationalNumber>,
+    met: Option<bool>,
+) -> (RichText, Style) {
+    let counts = format!(
+        "yes {} / no {} / abstain {} lovelace",
+        tally.yes, tally.no, tally.abstain
+    );
+    let Some(threshold) = threshold else {
+        return (
+            RichText::Single(Span::raw(format!("{} (not voting)", counts))),
+            Style::default().fg(Color::DarkGray),
+        );
+    };
+    let ratio = ratio.map_or("-".to_string(), |r| format!("{:.2}%", r * 100.0));
+    let style = match met {
+        Some(true) => Style::default().fg(Color::Green),
+        _ => Style::default().fg(Color::Red),
+    };
+    (
+        RichText::Single(Span::raw(format!(
+            "{}, {} yes for a threshold of {}",
+            counts,
+            ratio,
+            RationalNumberDisplay(threshold)
+        ))),
+        style,
+    )
+}
+
+impl ToRichText for ProposalTally {
+    fn to_rich_text(&self) -> RichText {
+        let (_, row) = &self.proposal;
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single(
+            "Proposal",
+            ComparableProposalIdDisplay(self.id()),
+        ));
+        lines.extend(labeled_default_single(
+            "Type",
+            gov_action_type(&row.proposal.gov_action),
+        ));
+        lines.extend(labeled_default_single(
+            "Expires After Epoch",
+            if self.expired {
+                format!("{} (expired)", row.valid_until)
+            } else {
+                row.valid_until.to_string()
+            },
+        ));
+
+        let (text, style) = stake_tally_text(
+            &self.drep,
+            self.drep_ratio(),
+            self.thresholds.drep.as_ref(),
+            self.drep_met(),
+        );
+        lines.extend(labeled("DReps".to_string(), text, style));
+        let (text, style) = stake_tally_text(
+            &self.spo,
+            self.spo_ratio(),
+            self.thresholds.spo.as_ref(),
+            self.spo_met(),
+        );
+        lines.extend(labeled("SPOs".to_string(), text, style));
+        lines.extend(labeled(
+            "Committee".to_string(),
+            RichText::Single(Span::raw(format!(
+                "yes {} / no {} / abstain {} members{}",
+                self.committee.yes,
+                self.committee.no,
+                self.committee.abstain,
+                if self.thresholds.committee {
+                    ""
+                } else {
+                    " (not voting)"
+                }
+            ))),
+            Style::default(),
+        ));
+        lines.extend(labeled_default_single(
+            "Thresholds Met",
+            if self.thresholds_met() { "yes" } else { "no" },
+        ));
+
+        lines.extend(labeled_default_single("Votes", self.votes.len()));
+        for (voter, vote) in &self.votes {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {}: ", VoterDisplay(voter))),
+                Span::styled(format!("{:?}", vote), vote_style(vote)),
+            ]));
+        }
+        RichText::Lines(lines)
+    }
+}
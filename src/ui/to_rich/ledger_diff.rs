@@ -0,0 +1,18 @@
+use crate::{
+    model::ledger_diff::DiffEntry,
+    ui::{RichText, ToRichText},
+};
+
+impl ToRichText for DiffEntry {
+    fn to_rich_text(&self) -> RichText {
+        match self {
+            Self::Account(item) => item.to_rich_text(),
+            Self::Pool(item) => item.to_rich_text(),
+            Self::DRep(item) => item.to_rich_text(),
+            Self::Proposal(item) => item.to_rich_text(),
+            Self::BlockIssuer(item) => item.to_rich_text(),
+            Self::CommitteeMember(item) => item.to_rich_text(),
+            Self::Utxo(item) => item.to_rich_text(),
+        }
+    }
+}
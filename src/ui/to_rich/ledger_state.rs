@@ -0,0 +1,46 @@
+use crate::{
+    model::ledger_state::LedgerStateField,
+    ui::{RichText, ToRichText, labeled, labeled_default_single},
+};
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+fn value_text(value: &[String], style: Style) -> RichText {
+    match value {
+        [single] => RichText::Single(Span::raw(single.clone())),
+        lines => lines
+            .iter()
+            .map(|line| Line::styled(line.clone(), style))
+            .collect(),
+    }
+}
+
+impl ToRichText for LedgerStateField {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single("Name", &self.name));
+
+        let value_style = if self.changed() {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.extend(labeled(
+            "Value".to_string(),
+            value_text(&self.value, value_style),
+            value_style,
+        ));
+
+        if let Some(initial) = &self.initial {
+            let initial_style = Style::default().fg(Color::DarkGray);
+            lines.extend(labeled(
+                "Initial Value".to_string(),
+                value_text(initial, initial_style),
+                initial_style,
+            ));
+        }
+        RichText::Lines(lines)
+    }
+}
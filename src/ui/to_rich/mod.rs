@@ -3,12 +3,18 @@ use ratatui::text::{Line, Span};
 
 pub mod account;
 pub mod block_issuer;
+pub mod committee;
 pub mod drep;
+pub mod governance;
 pub mod header;
+pub mod ledger_diff;
+pub mod ledger_state;
 pub mod nonces;
 pub mod pool;
 pub mod proposal;
 pub mod span;
+pub mod stake;
+pub mod transaction;
 pub mod utxo;
 
 impl From<Vec<Line<'static>>> for RichText {
@@ -1,6 +1,9 @@
-use crate::ui::{
-    RationalNumberDisplay, RichText, ToRichText, labeled_default, labeled_default_opt,
-    labeled_default_opt_single, labeled_default_single, to_list_item::ProposalItem,
+use crate::{
+    model::proposal::ProposalEntry,
+    ui::{
+        RationalNumberDisplay, RichText, ToRichText, labeled_default, labeled_default_opt,
+        labeled_default_opt_single, labeled_default_single, to_list_item::ProposalItem,
+    },
 };
 use amaru_kernel::{
     ComparableProposalId, CostModel, CostModels, ExUnitPrices, ExUnits, GovAction,
@@ -57,6 +60,24 @@ impl ToRichText for ProposalItem {
     }
 }
 
+impl ToRichText for ProposalEntry {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single(
+            "Proposal",
+            ComparableProposalIdDisplay(&self.item.0),
+        ));
+        if let Some(epoch) = self.epoch {
+            lines.extend(labeled_default_single("Epoch", epoch));
+        }
+        if let Some(status) = self.status {
+            lines.extend(labeled_default_single("Status", status));
+        }
+        lines.extend(self.item.1.to_rich_text().unwrap_lines());
+        RichText::Lines(lines)
+    }
+}
+
 impl ToRichText for proposals::Row {
     fn to_rich_text(&self) -> RichText {
         let mut lines = Vec::new();
@@ -105,6 +126,19 @@ impl ToRichText for Proposal {
     }
 }
 
+/// The name of the kind of a governance action.
+pub fn gov_action_type(action: &GovAction) -> &'static str {
+    match action {
+        GovAction::ParameterChange(..) => "ParameterChange",
+        GovAction::HardForkInitiation(..) => "HardForkInitiation",
+        GovAction::TreasuryWithdrawals(..) => "TreasuryWithdrawals",
+        GovAction::NoConfidence(..) => "NoConfidence",
+        GovAction::UpdateCommittee(..) => "UpdateCommittee",
+        GovAction::NewConstitution(..) => "NewConstitution",
+        GovAction::Information => "Information",
+    }
+}
+
 impl ToRichText for GovAction {
     fn to_rich_text(&self) -> RichText {
         let mut lines = vec![];
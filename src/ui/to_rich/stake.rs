@@ -0,0 +1,35 @@
+use crate::{
+    model::stake::PoolStake,
+    ui::{RichText, ToRichText, labeled, labeled_default_single},
+};
+use ratatui::{
+    style::{Color, Style},
+    text::Span,
+};
+
+impl ToRichText for PoolStake {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single("Rank", self.rank));
+        lines.extend(labeled_default_single("Pool", self.pool_id));
+        lines.extend(labeled_default_single(
+            "Stake",
+            format!("{} lovelace", self.stake),
+        ));
+        lines.extend(labeled_default_single(
+            "Share",
+            format!("{:.3}%", self.share * 100.0),
+        ));
+        let saturation_style = if self.saturation > 1.0 {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default()
+        };
+        lines.extend(labeled(
+            "Saturation".to_string(),
+            RichText::Single(Span::raw(format!("{:.1}%", self.saturation * 100.0))),
+            saturation_style,
+        ));
+        RichText::Lines(lines)
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    model::block::TransactionSummary,
+    ui::{
+        RationalNumberDisplay, RichText, ToRichText, labeled, labeled_default,
+        labeled_default_opt_single, labeled_default_single, to_rich::utxo::TransactionInputDisplay,
+    },
+};
+use amaru_kernel::{Address, Certificate, TransactionInput};
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+fn inputs_rich_text(inputs: &[TransactionInput]) -> RichText {
+    if inputs.is_empty() {
+        return RichText::Single(Span::raw("None"));
+    }
+    inputs
+        .iter()
+        .map(|i| Line::from(TransactionInputDisplay(i).to_string()))
+        .collect()
+}
+
+fn reward_account_to_string(account: &[u8]) -> String {
+    Address::from_bytes(account)
+        .ok()
+        .and_then(|a| a.to_bech32().ok())
+        .unwrap_or_else(|| hex::encode(account))
+}
+
+impl ToRichText for TransactionSummary {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default("Id", &self.id));
+        lines.extend(labeled_default_single("Index", self.index));
+        lines.extend(labeled(
+            "Valid".to_string(),
+            RichText::Single(Span::raw(self.is_valid.to_string())),
+            Style::default().fg(if self.is_valid {
+                Color::Green
+            } else {
+                Color::Red
+            }),
+        ));
+        lines.extend(labeled_default_single(
+            "Fee",
+            format!("{} lovelace", self.fee),
+        ));
+        lines.extend(labeled_default_opt_single(
+            "Validity Start",
+            self.validity_start,
+        ));
+        lines.extend(labeled_default_opt_single("TTL", self.ttl));
+
+        lines.extend(labeled(
+            "Inputs".to_string(),
+            inputs_rich_text(&self.inputs),
+            Style::default(),
+        ));
+        if !self.reference_inputs.is_empty() {
+            lines.extend(labeled(
+                "Reference Inputs".to_string(),
+                inputs_rich_text(&self.reference_inputs),
+                Style::default(),
+            ));
+        }
+        if !self.collateral.is_empty() {
+            lines.extend(labeled(
+                "Collateral".to_string(),
+                inputs_rich_text(&self.collateral),
+                Style::default(),
+            ));
+        }
+
+        for (ix, output) in self.outputs.iter().enumerate() {
+            let (status, style) = match self.unspent_outputs.as_ref().and_then(|u| u.get(ix)) {
+                Some(true) => ("unspent", Style::default().fg(Color::Green)),
+                Some(false) => ("not in UTXO set", Style::default().fg(Color::DarkGray)),
+                None => ("", Style::default()),
+            };
+            lines.extend(labeled(
+                format!("Output #{ix}"),
+                RichText::Single(Span::raw(status)),
+                style,
+            ));
+            lines.extend(output.to_rich_text().unwrap_lines());
+        }
+
+        for (ix, certificate) in self.certificates.iter().enumerate() {
+            lines.extend(labeled(
+                format!("Certificate #{ix}"),
+                certificate.to_rich_text(),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
+        if !self.withdrawals.is_empty() {
+            lines.extend(labeled(
+                "Withdrawals".to_string(),
+                self.withdrawals
+                    .iter()
+                    .map(|(account, amount)| {
+                        Line::from(format!(
+                            "{} lovelace from {}",
+                            amount,
+                            reward_account_to_string(account)
+                        ))
+                    })
+                    .collect(),
+                Style::default(),
+            ));
+        }
+
+        if !self.mint.is_empty() {
+            lines.extend(labeled(
+                "Mint".to_string(),
+                self.mint
+                    .iter()
+                    .map(|(policy, name, amount)| {
+                        Line::from(format!("{} {}@{}", amount, name, policy))
+                    })
+                    .collect(),
+                Style::default(),
+            ));
+        }
+
+        let witnesses = &self.witnesses;
+        lines.extend(labeled(
+            "VKey Witnesses".to_string(),
+            if witnesses.vkeys.is_empty() {
+                RichText::Single(Span::raw("None"))
+            } else {
+                witnesses
+                    .vkeys
+                    .iter()
+                    .map(|vkey| Line::from(vkey.to_string()))
+                    .collect()
+            },
+            Style::default(),
+        ));
+        lines.extend(labeled_default_single(
+            "Bootstrap Witnesses",
+            witnesses.bootstraps,
+        ));
+        lines.extend(labeled_default_single(
+            "Native Scripts",
+            witnesses.native_scripts,
+        ));
+        lines.extend(labeled_default_single(
+            "Plutus Scripts",
+            witnesses.plutus_scripts,
+        ));
+        lines.extend(labeled_default_single("Plutus Data", witnesses.plutus_data));
+        lines.extend(labeled_default_single("Redeemers", witnesses.has_redeemers));
+
+        RichText::Lines(lines)
+    }
+}
+
+impl ToRichText for Certificate {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        match self {
+            Certificate::StakeRegistration(credential) => {
+                lines.extend(labeled_default_single("Type", "Stake Registration"));
+                lines.extend(labeled_default("Credential", credential));
+            }
+            Certificate::StakeDeregistration(credential) => {
+                lines.extend(labeled_default_single("Type", "Stake Deregistration"));
+                lines.extend(labeled_default("Credential", credential));
+            }
+            Certificate::StakeDelegation(credential, pool) => {
+                lines.extend(labeled_default_single("Type", "Stake Delegation"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default("Pool", pool));
+            }
+            Certificate::PoolRegistration {
+                operator,
+                vrf_keyhash,
+                pledge,
+                cost,
+                margin,
+                reward_account,
+                pool_owners,
+                relays,
+                pool_metadata,
+            } => {
+                lines.extend(labeled_default_single("Type", "Pool Registration"));
+                lines.extend(labeled_default("Operator", operator));
+                lines.extend(labeled_default("VRF Keyhash", vrf_keyhash));
+                lines.extend(labeled_default_single(
+                    "Pledge",
+                    format!("{} lovelace", pledge),
+                ));
+                lines.extend(labeled_default_single("Cost", format!("{} lovelace", cost)));
+                lines.extend(labeled_default_single(
+                    "Margin",
+                    RationalNumberDisplay(margin),
+                ));
+                lines.extend(labeled_default_single(
+                    "Reward Account",
+                    reward_account_to_string(reward_account),
+                ));
+                lines.extend(labeled_default_single("Owners", pool_owners.len()));
+                lines.extend(labeled_default_single("Relays", relays.len()));
+                lines.extend(labeled_default("Metadata", pool_metadata));
+            }
+            Certificate::PoolRetirement(pool, epoch) => {
+                lines.extend(labeled_default_single("Type", "Pool Retirement"));
+                lines.extend(labeled_default("Pool", pool));
+                lines.extend(labeled_default_single("Epoch", epoch));
+            }
+            Certificate::Reg(credential, deposit) => {
+                lines.extend(labeled_default_single("Type", "Registration"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default_single(
+                    "Deposit",
+                    format!("{} lovelace", deposit),
+                ));
+            }
+            Certificate::UnReg(credential, refund) => {
+                lines.extend(labeled_default_single("Type", "Unregistration"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default_single(
+                    "Refund",
+                    format!("{} lovelace", refund),
+                ));
+            }
+            Certificate::VoteDeleg(credential, drep) => {
+                lines.extend(labeled_default_single("Type", "Vote Delegation"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(drep.to_rich_text().unwrap_lines());
+            }
+            Certificate::StakeVoteDeleg(credential, pool, drep) => {
+                lines.extend(labeled_default_single("Type", "Stake and Vote Delegation"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default("Pool", pool));
+                lines.extend(drep.to_rich_text().unwrap_lines());
+            }
+            Certificate::StakeRegDeleg(credential, pool, deposit) => {
+                lines.extend(labeled_default_single(
+                    "Type",
+                    "Registration and Stake Delegation",
+                ));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default("Pool", pool));
+                lines.extend(labeled_default_single(
+                    "Deposit",
+                    format!("{} lovelace", deposit),
+                ));
+            }
+            Certificate::VoteRegDeleg(credential, drep, deposit) => {
+                lines.extend(labeled_default_single(
+                    "Type",
+                    "Registration and Vote Delegation",
+                ));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(drep.to_rich_text().unwrap_lines());
+                lines.extend(labeled_default_single(
+                    "Deposit",
+                    format!("{} lovelace", deposit),
+                ));
+            }
+            Certificate::StakeVoteRegDeleg(credential, pool, drep, deposit) => {
+                lines.extend(labeled_default_single(
+                    "Type",
+                    "Registration, Stake and Vote Delegation",
+                ));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default("Pool", pool));
+                lines.extend(drep.to_rich_text().unwrap_lines());
+                lines.extend(labeled_default_single(
+                    "Deposit",
+                    format!("{} lovelace", deposit),
+                ));
+            }
+            Certificate::AuthCommitteeHot(cold, hot) => {
+                lines.extend(labeled_default_single(
+                    "Type",
+                    "Committee Hot Key Authorization",
+                ));
+                lines.extend(labeled_default("Cold Credential", cold));
+                lines.extend(labeled_default("Hot Credential", hot));
+            }
+            Certificate::ResignCommitteeCold(cold, anchor) => {
+                lines.extend(labeled_default_single("Type", "Committee Resignation"));
+                lines.extend(labeled_default("Cold Credential", cold));
+                lines.extend(labeled_default("Anchor", anchor));
+            }
+            Certificate::RegDRepCert(credential, deposit, anchor) => {
+                lines.extend(labeled_default_single("Type", "DRep Registration"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default_single(
+                    "Deposit",
+                    format!("{} lovelace", deposit),
+                ));
+                lines.extend(labeled_default("Anchor", anchor));
+            }
+            Certificate::UnRegDRepCert(credential, refund) => {
+                lines.extend(labeled_default_single("Type", "DRep Unregistration"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default_single(
+                    "Refund",
+                    format!("{} lovelace", refund),
+                ));
+            }
+            Certificate::UpdateDRepCert(credential, anchor) => {
+                lines.extend(labeled_default_single("Type", "DRep Update"));
+                lines.extend(labeled_default("Credential", credential));
+                lines.extend(labeled_default("Anchor", anchor));
+            }
+        }
+        RichText::Lines(lines)
+    }
+}
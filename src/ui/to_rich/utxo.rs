@@ -1,5 +1,9 @@
-use crate::ui::{
-    RichText, ToRichText, labeled, labeled_default, labeled_default_opt, labeled_default_single,
+use crate::{
+    model::utxo_totals::UtxoTotals,
+    ui::{
+        RichText, ToRichText, labeled, labeled_default, labeled_default_opt,
+        labeled_default_opt_single, labeled_default_single,
+    },
 };
 use amaru_kernel::{
     Address, MemoizedDatum, MemoizedScript, MemoizedTransactionOutput, PostAlonzoTransactionOutput,
@@ -245,3 +249,49 @@ impl<'a, T: minicbor::Encode<()>> ToRichText for CborWrapRichText<'a, T> {
         }
     }
 }
+
+impl ToRichText for UtxoTotals {
+    fn to_rich_text(&self) -> RichText {
+        let mut lines = Vec::new();
+        lines.extend(labeled_default_single(
+            "Outputs",
+            if self.is_loading {
+                format!("{} (loading...)", self.count)
+            } else {
+                self.count.to_string()
+            },
+        ));
+        lines.extend(labeled_default_single(
+            "Total",
+            format!("{} lovelace", self.lovelace),
+        ));
+        lines.extend(labeled(
+            "Assets".to_string(),
+            if self.assets.is_empty() {
+                RichText::Single(Span::raw("None"))
+            } else {
+                self.assets
+                    .iter()
+                    .map(|((policy, name), amount)| {
+                        Line::from(format!("{} {}@{}", amount, name, policy))
+                    })
+                    .collect()
+            },
+            Style::default(),
+        ));
+        lines.extend(labeled_default_single("With Datum", self.with_datum));
+        lines.extend(labeled_default_single(
+            "With Script Ref",
+            self.with_script_ref,
+        ));
+        lines.extend(labeled_default_opt_single(
+            "Min Size (bytes)",
+            self.min_size,
+        ));
+        lines.extend(labeled_default_opt_single(
+            "Max Size (bytes)",
+            self.max_size,
+        ));
+        RichText::Lines(lines)
+    }
+}
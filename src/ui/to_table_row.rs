@@ -0,0 +1,219 @@
+use crate::{
+    model::proposal::ProposalEntry,
+    ui::{
+        RationalNumberDisplay,
+        to_list_item::{
+            AccountItem, BlockIssuerItem, CommitteeMemberItem, DRepItem, PoolItem, ProposalItem,
+            UtxoItem,
+        },
+        to_rich::{
+            account::StakeCredentialDisplay,
+            proposal::{ComparableProposalIdDisplay, gov_action_type},
+            utxo::TransactionInputDisplay,
+        },
+    },
+};
+use amaru_kernel::{DRep, MemoizedDatum, Value};
+
+/// Renders an entity as a row of cells, for lists drawn as a table.
+pub trait ToTableRow {
+    /// The column headers, with their default widths.
+    const COLUMNS: &'static [(&'static str, u16)];
+
+    /// One cell per column, in the order of `COLUMNS`.
+    fn to_table_row(&self) -> Vec<String>;
+
+    /// The primary identifier of the entity, its first column.
+    fn identifier(&self) -> String {
+        self.to_table_row().into_iter().next().unwrap_or_default()
+    }
+}
+
+pub(crate) fn drep_cell(drep: &DRep) -> String {
+    match drep {
+        DRep::Key(hash) => format!("Key({})", hash),
+        DRep::Script(hash) => format!("Script({})", hash),
+        DRep::Abstain => "Abstain".to_string(),
+        DRep::NoConfidence => "NoConfidence".to_string(),
+    }
+}
+
+fn none_cell() -> String {
+    "-".to_string()
+}
+
+impl ToTableRow for AccountItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Credential", 58),
+        ("Deposit", 12),
+        ("Rewards", 16),
+        ("Pool", 58),
+        ("DRep", 66),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (credential, row) = self;
+        vec![
+            StakeCredentialDisplay(credential).to_string(),
+            row.deposit.to_string(),
+            row.rewards.to_string(),
+            row.pool
+                .map_or_else(none_cell, |(pool_id, _)| pool_id.to_string()),
+            row.drep
+                .as_ref()
+                .map_or_else(none_cell, |(drep, _)| drep_cell(drep)),
+        ]
+    }
+}
+
+impl ToTableRow for BlockIssuerItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[("Slot", 12), ("Slot Leader", 58)];
+
+    fn to_table_row(&self) -> Vec<String> {
+        vec![self.0.to_string(), self.1.slot_leader.to_string()]
+    }
+}
+
+impl ToTableRow for CommitteeMemberItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Cold Credential", 58),
+        ("Hot Credential", 58),
+        ("Expires At", 12),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (cold, row) = self;
+        vec![
+            StakeCredentialDisplay(cold).to_string(),
+            row.hot_credential
+                .as_ref()
+                .map_or_else(none_cell, |hot| StakeCredentialDisplay(hot).to_string()),
+            row.expires_at
+                .map_or_else(none_cell, |epoch| epoch.to_string()),
+        ]
+    }
+}
+
+impl ToTableRow for DRepItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Credential", 58),
+        ("Deposit", 16),
+        ("Valid Until", 12),
+        ("Anchor", 40),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (credential, row) = self;
+        vec![
+            StakeCredentialDisplay(credential).to_string(),
+            row.deposit.to_string(),
+            row.valid_until.to_string(),
+            row.anchor
+                .as_ref()
+                .map_or_else(none_cell, |anchor| anchor.url.clone()),
+        ]
+    }
+}
+
+impl ToTableRow for PoolItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Pool ID", 58),
+        ("Pledge", 18),
+        ("Cost", 12),
+        ("Margin", 10),
+        ("Retiring Epoch", 14),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (pool_id, row) = self;
+        let params = &row.current_params;
+        let retirement = row
+            .future_params
+            .iter()
+            .find(|(params, _)| params.is_none())
+            .map(|(_, epoch)| epoch);
+        vec![
+            pool_id.to_string(),
+            params.pledge.to_string(),
+            params.cost.to_string(),
+            RationalNumberDisplay(&params.margin).to_string(),
+            retirement.map_or_else(none_cell, |epoch| epoch.to_string()),
+        ]
+    }
+}
+
+impl ToTableRow for ProposalItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Proposal ID", 68),
+        ("Type", 20),
+        ("Proposed In Slot", 16),
+        ("Valid Until", 12),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (id, row) = self;
+        vec![
+            ComparableProposalIdDisplay(id).to_string(),
+            gov_action_type(&row.proposal.gov_action).to_string(),
+            row.proposed_in.transaction.slot.to_string(),
+            row.valid_until.to_string(),
+        ]
+    }
+}
+
+impl ToTableRow for ProposalEntry {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Proposal ID", 68),
+        ("Type", 20),
+        ("Epoch", 6),
+        ("Status", 8),
+        ("Proposed In Slot", 16),
+        ("Valid Until", 12),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (id, row) = &self.item;
+        vec![
+            ComparableProposalIdDisplay(id).to_string(),
+            gov_action_type(&row.proposal.gov_action).to_string(),
+            self.epoch.map_or_else(none_cell, |epoch| epoch.to_string()),
+            self.status
+                .map_or_else(none_cell, |status| status.to_string()),
+            row.proposed_in.transaction.slot.to_string(),
+            row.valid_until.to_string(),
+        ]
+    }
+}
+
+impl ToTableRow for UtxoItem {
+    const COLUMNS: &'static [(&'static str, u16)] = &[
+        ("Input", 68),
+        ("Lovelace", 16),
+        ("Assets", 8),
+        ("Datum", 6),
+        ("Script", 6),
+        ("Address", 60),
+    ];
+
+    fn to_table_row(&self) -> Vec<String> {
+        let (input, output) = self;
+        let (lovelace, assets) = match &output.value {
+            Value::Coin(coin) => (*coin, 0),
+            Value::Multiasset(coin, assets) => {
+                (*coin, assets.iter().map(|(_, aset)| aset.len()).sum())
+            }
+        };
+        let flag = |set: bool| if set { "yes" } else { "no" }.to_string();
+        vec![
+            TransactionInputDisplay(input).to_string(),
+            lovelace.to_string(),
+            assets.to_string(),
+            flag(!matches!(output.datum, MemoizedDatum::None)),
+            flag(output.script.is_some()),
+            output
+                .address
+                .to_bech32()
+                .unwrap_or_else(|_| hex::encode(output.address.to_vec())),
+        ]
+    }
+}
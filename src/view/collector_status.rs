@@ -0,0 +1,37 @@
+use crate::otel::{graph::TraceGraph, service::CollectorStatus};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Draws where the collector gets its traces from, followed by its errors.
+pub fn draw_collector_status(frame: &mut Frame<'_>, area: Rect, status: &CollectorStatus) {
+    let (text, color) = if status.errors.is_empty() {
+        (status.source.clone(), Color::Gray)
+    } else {
+        let errors = status.errors.join(" · ");
+        (format!("{} · {}", status.source, errors), Color::Red)
+    };
+    let widget = Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .block(Block::default().title("Collector").borders(Borders::ALL));
+    frame.render_widget(widget, area);
+}
+
+/// Draws how much the trace graph holds, and how much was evicted to stay
+/// within the retention and budgets.
+pub fn draw_store_stats(frame: &mut Frame<'_>, area: Rect, graph: &TraceGraph) {
+    let text = format!(
+        "{} spans · {:.1} MiB · evicted {} traces, {} spans",
+        graph.spans.len(),
+        graph.approx_bytes() as f64 / (1024.0 * 1024.0),
+        graph.evicted.traces,
+        graph.evicted.spans
+    );
+    let widget = Paragraph::new(text)
+        .style(Style::default().fg(Color::Gray))
+        .block(Block::default().title("Trace store").borders(Borders::ALL));
+    frame.render_widget(widget, area);
+}
@@ -1,9 +1,9 @@
 use crate::ui::{RichText, ToRichText};
 use ratatui::{
     Frame,
-    layout::Rect,
-    style::{Color, Style},
-    text::Span,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 
@@ -14,6 +14,18 @@ pub fn draw_details<T: ToRichText>(
     title: String,
     item_opt: Option<&T>,
     is_focused: bool,
+) {
+    draw_details_scrolled(frame, area, title, item_opt, is_focused, 0);
+}
+
+/// Same as `draw_details`, skipping the first `scroll` lines.
+pub fn draw_details_scrolled<T: ToRichText>(
+    frame: &mut Frame,
+    area: Rect,
+    title: String,
+    item_opt: Option<&T>,
+    is_focused: bool,
+    scroll: u16,
 ) {
     let mut block = Block::default().title(title).borders(Borders::ALL);
     if is_focused {
@@ -27,6 +39,64 @@ pub fn draw_details<T: ToRichText>(
         .unwrap_or(RichText::Single(Span::raw("Nothing selected")))
         .unwrap_lines();
 
-    let widget = Paragraph::new(lines).wrap(Wrap { trim: true }).block(block);
+    let widget = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0))
+        .block(block);
     frame.render_widget(widget, area);
 }
+
+/// Draws two versions of an item next to each other, highlighting the lines
+/// that are not on the other side.
+pub fn draw_side_by_side<T: ToRichText>(
+    frame: &mut Frame,
+    area: Rect,
+    titles: (&str, &str),
+    items: (Option<&T>, Option<&T>),
+    is_focused: bool,
+    scroll: u16,
+) {
+    let to_lines = |item: Option<&T>| {
+        item.map(|i| i.to_rich_text())
+            .unwrap_or(RichText::Single(Span::styled(
+                "Missing",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .unwrap_lines()
+    };
+    let left = to_lines(items.0);
+    let right = to_lines(items.1);
+    let left_text: Vec<String> = left.iter().map(Line::to_string).collect();
+    let right_text: Vec<String> = right.iter().map(Line::to_string).collect();
+
+    let [left_area, right_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+    for (area, title, lines, other) in [
+        (left_area, titles.0, left, &right_text),
+        (right_area, titles.1, right, &left_text),
+    ] {
+        let mut block = Block::default()
+            .title(title.to_string())
+            .borders(Borders::ALL);
+        if is_focused {
+            block = block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+        let lines: Vec<Line> = lines
+            .into_iter()
+            .map(|line| {
+                if other.contains(&line.to_string()) {
+                    line
+                } else {
+                    line.patch_style(Style::default().add_modifier(Modifier::REVERSED))
+                }
+            })
+            .collect();
+        let widget = Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .scroll((scroll, 0))
+            .block(block);
+        frame.render_widget(widget, area);
+    }
+}
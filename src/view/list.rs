@@ -1,8 +1,12 @@
-use crate::{model::buffer_list::BufferList, ui::to_list_item::ToListItem};
+use crate::{
+    model::{buffer_list::BufferList, table_columns::TableColumns},
+    ui::{to_list_item::ToListItem, to_table_row::ToTableRow},
+};
 use ratatui::{
+    layout::Constraint,
     prelude::{Frame, Rect},
-    style::{Color, Style},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, List, ListItem, ListState, Row, Table, TableState},
 };
 use tracing::debug;
 
@@ -231,10 +235,43 @@ impl ListViewState {
         debug!("ListViewState: advance_window - AFTER: {:?}", self);
     }
 
+    /// The items the view shows, from the offset on. Rows are only built for
+    /// these, a buffer can hold far more items than fit on screen.
+    fn window<'a, T>(&self, items: &'a [T]) -> &'a [T] {
+        let start = self.offset.min(items.len());
+        let end = if self.height == 0 {
+            items.len()
+        } else {
+            (start + self.height).min(items.len())
+        };
+        &items[start..end]
+    }
+
+    /// The widget state for the items of `window`.
+    fn window_selection(&self) -> Option<usize> {
+        self.selected.checked_sub(self.offset)
+    }
+
     pub fn draw<T, B>(&self, f: &mut Frame, area: Rect, data: &B, is_focused: bool)
     where
         T: ToListItem,
         B: BufferList<T>,
+    {
+        self.draw_styled(f, area, data, is_focused, |_| None);
+    }
+
+    /// Same as `draw`, overriding the style of the items `row_style` returns
+    /// one for.
+    pub fn draw_styled<T, B>(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        data: &B,
+        is_focused: bool,
+        row_style: impl Fn(&T) -> Option<Style>,
+    ) where
+        T: ToListItem,
+        B: BufferList<T>,
     {
         let mut block = Block::default().borders(Borders::ALL).title(self.title);
         if is_focused {
@@ -243,14 +280,91 @@ impl ListViewState {
                 .title_style(Style::default().fg(Color::White));
         }
 
-        let items: Vec<ListItem> = data.buffer().iter().map(ToListItem::to_list_item).collect();
+        let items: Vec<ListItem> = self
+            .window(data.buffer())
+            .iter()
+            .map(|item| match row_style(item) {
+                Some(style) => item.to_list_item().style(style),
+                None => item.to_list_item(),
+            })
+            .collect();
 
         let list_widget = List::new(items).block(block).highlight_symbol(">> ");
 
-        let mut list_state = ListState::default()
-            .with_offset(self.offset)
-            .with_selected(Some(self.selected));
+        let mut list_state = ListState::default().with_selected(self.window_selection());
 
         f.render_stateful_widget(list_widget, area, &mut list_state);
     }
+
+    /// Draws the data as a table, with only the visible columns. The header of
+    /// the selected column is highlighted while focused.
+    pub fn draw_table<T, B>(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        data: &B,
+        columns: &TableColumns,
+        is_focused: bool,
+    ) where
+        T: ToTableRow,
+        B: BufferList<T>,
+    {
+        self.draw_table_styled(f, area, data, columns, is_focused, |_| None);
+    }
+
+    /// Same as `draw_table`, styling the rows `row_style` returns a style for.
+    pub fn draw_table_styled<T, B>(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        data: &B,
+        columns: &TableColumns,
+        is_focused: bool,
+        row_style: impl Fn(&T) -> Option<Style>,
+    ) where
+        T: ToTableRow,
+        B: BufferList<T>,
+    {
+        let mut block = Block::default().borders(Borders::ALL).title(self.title);
+        if is_focused {
+            block = block
+                .border_style(Style::default().fg(Color::Blue))
+                .title_style(Style::default().fg(Color::White));
+        }
+
+        let visible: Vec<usize> = columns.visible().collect();
+        let header_style = Style::default().add_modifier(Modifier::BOLD);
+        let header = Row::new(visible.iter().map(|i| {
+            let style = if is_focused && *i == columns.selected {
+                header_style.fg(Color::Yellow)
+            } else {
+                header_style
+            };
+            Cell::from(columns.headers[*i]).style(style)
+        }));
+        let rows = self.window(data.buffer()).iter().map(|item| {
+            let mut cells = item.to_table_row();
+            let row = Row::new(
+                visible
+                    .iter()
+                    .map(|i| Cell::from(std::mem::take(&mut cells[*i]))),
+            );
+            match row_style(item) {
+                Some(style) => row.style(style),
+                None => row,
+            }
+        });
+        let widths = visible
+            .iter()
+            .map(|i| Constraint::Length(columns.widths[*i]));
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(block)
+            .highlight_symbol(">> ");
+
+        let mut table_state = TableState::default().with_selected(self.window_selection());
+
+        f.render_stateful_widget(table, area, &mut table_state);
+    }
 }
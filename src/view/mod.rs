@@ -1,3 +1,4 @@
+pub mod collector_status;
 pub mod empty_list;
 pub mod flame_graph;
 pub mod item_details;
@@ -5,3 +6,4 @@ pub mod list;
 pub mod search;
 pub mod span;
 pub mod span_bar;
+pub mod stake_chart;
@@ -0,0 +1,77 @@
+use crate::model::stake::PoolStake;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Bar, BarChart, BarGroup, Block, Borders},
+};
+
+const BAR_WIDTH: u16 = 5;
+const BAR_GAP: u16 = 1;
+const LOVELACE_PER_ADA: u64 = 1_000_000;
+
+fn format_compact(ada: u64) -> String {
+    match ada {
+        0..1_000 => ada.to_string(),
+        1_000..1_000_000 => format!("{}K", ada / 1_000),
+        1_000_000..1_000_000_000 => format!("{}M", ada / 1_000_000),
+        _ => format!("{}B", ada / 1_000_000_000),
+    }
+}
+
+/// Draws the stake of the pools as bars, in ADA, from the largest pool. The
+/// bars shift to keep the selected pool in view; saturated pools are red.
+pub fn draw_stake_chart(
+    frame: &mut Frame,
+    area: Rect,
+    pools: &[PoolStake],
+    selected: Option<usize>,
+    saturation_point: Option<u64>,
+    is_focused: bool,
+) {
+    let title = match saturation_point {
+        Some(point) => format!(
+            "Stake by Pool (saturation at {} ADA)",
+            format_compact(point / LOVELACE_PER_ADA)
+        ),
+        None => "Stake by Pool".to_string(),
+    };
+    let mut block = Block::default().title(title).borders(Borders::ALL);
+    if is_focused {
+        block = block
+            .border_style(Style::default().fg(Color::Blue))
+            .title_style(Style::default().fg(Color::White));
+    }
+
+    let capacity = (area.width.saturating_sub(2) / (BAR_WIDTH + BAR_GAP)).max(1) as usize;
+    let start = selected.map_or(0, |index| (index + 1).saturating_sub(capacity));
+    let bars: Vec<Bar> = pools
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(capacity)
+        .map(|(index, pool)| {
+            let color = if selected == Some(index) {
+                Color::Yellow
+            } else if pool.saturation > 1.0 {
+                Color::Red
+            } else {
+                Color::Cyan
+            };
+            let ada = pool.stake / LOVELACE_PER_ADA;
+            Bar::default()
+                .value(ada)
+                .text_value(format_compact(ada))
+                .label(Line::from(format!("#{}", pool.rank)))
+                .style(Style::default().fg(color))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(block)
+        .bar_width(BAR_WIDTH)
+        .bar_gap(BAR_GAP)
+        .data(BarGroup::default().bars(&bars));
+    frame.render_widget(chart, area);
+}